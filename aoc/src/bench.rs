@@ -0,0 +1,95 @@
+use common::{
+    parse::ParseError,
+    solution::{Part, Solution},
+};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Upper bound on how many times a stage is repeated, so that very fast stages finish promptly.
+const MAX_RUNS: u32 = 10_000;
+
+/// Saved timings in nanoseconds, keyed by day and then by stage.
+pub type Baseline = BTreeMap<String, BTreeMap<String, u64>>;
+
+/// Every stage timed for one day, in the order parse, part 1, part 2.
+pub type DayTimings = Vec<StageTiming>;
+
+/// How long one stage of a day, its parse or one of its parts, takes on average.
+pub struct StageTiming {
+    pub stage: String,
+    pub time: Duration,
+}
+
+/// Times parsing and each of the given parts, repeating each one until `budget` has been spent on it.
+pub fn time_day<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    budget: Duration,
+) -> Result<DayTimings, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut timings = vec![StageTiming {
+        stage: "parse".to_owned(),
+        time: time_stage(budget, || S::parse(black_box(input))),
+    }];
+    for part in parts {
+        let time = match part {
+            Part::One => time_stage(budget, || S::part1(black_box(&parsed))),
+            Part::Two => time_stage(budget, || S::part2(black_box(&parsed))),
+        };
+        timings.push(StageTiming {
+            stage: format!("part{}", part.number()),
+            time,
+        });
+    }
+    return Ok(timings);
+}
+
+fn time_stage<T>(budget: Duration, mut stage: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || (start.elapsed() < budget && runs < MAX_RUNS) {
+        black_box(stage());
+        runs += 1;
+    }
+    return start.elapsed() / runs;
+}
+
+pub fn day_key(day: u8) -> String {
+    format!("day-{:02}", day)
+}
+
+pub fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    toml::from_str(&contents).map_err(|err| format!("invalid baseline {}: {}", path.display(), err))
+}
+
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    let contents = toml::to_string(baseline).map_err(|err| err.to_string())?;
+    fs::write(path, contents).map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
+
+/// Formats a duration with a unit suited to its size.
+pub struct HumanDuration(pub Duration);
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = if nanos < 1e3 {
+            (nanos, "ns")
+        } else if nanos < 1e6 {
+            (nanos / 1e3, "µs")
+        } else if nanos < 1e9 {
+            (nanos / 1e6, "ms")
+        } else {
+            (nanos / 1e9, "s")
+        };
+        let text = format!("{:.1} {}", value, unit);
+        f.pad(&text)
+    }
+}
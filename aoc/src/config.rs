@@ -0,0 +1,166 @@
+use crate::bench::day_key;
+use common::{parse::ParseError, solution::ConfiguredSolution};
+use std::{fmt, fs, path::Path};
+
+/// One day's puzzle constants as given in the config file and on the command line, before they're
+/// checked against the day's own config type.
+#[derive(Clone, Debug, Default)]
+pub struct Settings {
+    /// Whether the example is being solved, so that its constants are the defaults.
+    pub example: bool,
+    pub values: toml::Table,
+}
+
+impl Settings {
+    /// Takes the day's table from the config file, then the `name=value` overrides on top of it.
+    pub fn for_day(
+        file: &toml::Table,
+        day: u8,
+        example: bool,
+        overrides: &[(String, toml::Value)],
+    ) -> Settings {
+        let mut values = match file.get(&day_key(day)) {
+            Some(toml::Value::Table(table)) => table.clone(),
+            _ => toml::Table::new(),
+        };
+        values.extend(overrides.iter().cloned());
+        return Settings { example, values };
+    }
+}
+
+/// Why a day couldn't be answered with the given settings.
+#[derive(Debug)]
+pub enum SolveError {
+    Config(String),
+    Parse(ParseError),
+}
+
+impl SolveError {
+    /// Describes the error, pointing into the input if it's a parse error.
+    pub fn report(&self, input: &str) -> String {
+        match self {
+            SolveError::Config(message) => format!("error: {}", message),
+            SolveError::Parse(err) => err.report(input),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Config(message) => write!(f, "{}", message),
+            SolveError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+/// Builds a day's config from its defaults, or the example's, with the settings laid over them.
+pub fn configure<S: ConfiguredSolution>(settings: &Settings) -> Result<S::Config, SolveError> {
+    let defaults = if settings.example {
+        S::example_config()
+    } else {
+        S::Config::default()
+    };
+    let mut values =
+        toml::Table::try_from(defaults).map_err(|err| SolveError::Config(err.to_string()))?;
+    values.extend(settings.values.clone());
+    return values.try_into().map_err(|err: toml::de::Error| {
+        SolveError::Config(format!("invalid setting: {}", err.message()))
+    });
+}
+
+/// Reads a config file with a table of settings for each day, like `[day-15]`.
+pub fn read_config(path: &Path) -> Result<toml::Table, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    toml::from_str(&contents).map_err(|err| format!("invalid config {}: {}", path.display(), err))
+}
+
+/// Reads a `name=value` setting, where the value is written as in TOML, or is a bare string.
+pub fn parse_setting(text: &str) -> Result<(String, toml::Value), String> {
+    let (name, value) = text
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got {:?}", text))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("expected a name before the = in {:?}", text));
+    }
+    let value = value.trim();
+    let value = toml::from_str::<toml::Table>(&format!("value = {}", value))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_owned()));
+    return Ok((name.to_owned(), value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day_15::{Day15, Day15Config};
+
+    #[test]
+    fn settings_are_read_as_toml_values() {
+        assert_eq!(
+            parse_setting("target_row=10"),
+            Ok(("target_row".to_owned(), toml::Value::Integer(10)))
+        );
+        assert_eq!(
+            parse_setting("sand_origin = [490, 0]"),
+            Ok((
+                "sand_origin".to_owned(),
+                toml::Value::Array(vec![toml::Value::Integer(490), toml::Value::Integer(0)])
+            ))
+        );
+        assert_eq!(
+            parse_setting("name=bare words"),
+            Ok((
+                "name".to_owned(),
+                toml::Value::String("bare words".to_owned())
+            ))
+        );
+        assert!(parse_setting("target_row").is_err());
+        assert!(parse_setting("=10").is_err());
+    }
+
+    #[test]
+    fn overrides_win_over_the_file_which_wins_over_the_defaults() {
+        let file = toml::from_str::<toml::Table>("[day-15]\ntarget_row = 10\nmax_coordinate = 20")
+            .unwrap();
+        let overrides = [("max_coordinate".to_owned(), toml::Value::Integer(30))];
+        let config = configure::<Day15>(&Settings::for_day(&file, 15, false, &overrides)).unwrap();
+        assert_eq!((config.target_row, config.max_coordinate), (10, 30));
+
+        let config = configure::<Day15>(&Settings::for_day(&file, 14, false, &[])).unwrap();
+        let defaults = Day15Config::default();
+        assert_eq!(
+            (config.target_row, config.max_coordinate),
+            (defaults.target_row, defaults.max_coordinate)
+        );
+    }
+
+    #[test]
+    fn unknown_and_mistyped_settings_are_errors() {
+        let unknown = [("target_rw".to_owned(), toml::Value::Integer(10))];
+        let err = configure::<Day15>(&Settings::for_day(&toml::Table::new(), 15, false, &unknown))
+            .unwrap_err();
+        assert!(err.to_string().contains("target_rw"), "{}", err);
+
+        let mistyped = [(
+            "target_row".to_owned(),
+            toml::Value::String("ten".to_owned()),
+        )];
+        assert!(configure::<Day15>(&Settings::for_day(
+            &toml::Table::new(),
+            15,
+            false,
+            &mistyped
+        ))
+        .is_err());
+    }
+}
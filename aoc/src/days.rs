@@ -0,0 +1,227 @@
+use crate::{
+    bench::{time_day, DayTimings},
+    config::{configure, Settings, SolveError},
+};
+use common::{
+    answer::Answer,
+    generate::generate,
+    input::StreamError,
+    parse::ParseError,
+    solution::{solve, solve_with, watch, watch_with, ConfiguredSolution, Part},
+    visualize::Observer,
+};
+use std::{io::BufRead, time::Duration};
+
+/// Answers a part from an input read a line at a time.
+pub type StreamFn = fn(&mut dyn BufRead, Part) -> Result<Answer, StreamError>;
+
+/// A day's solution, with its puzzle constants taken from settings, for days that have any.
+pub struct Configured {
+    pub solve: fn(&str, Part, &Settings) -> Result<Answer, SolveError>,
+    pub watch: fn(&str, Part, &Settings, &mut dyn Observer) -> Result<Answer, SolveError>,
+}
+
+/// A day's solution with its types erased, so every day can be driven from one table.
+pub struct Day {
+    pub number: u8,
+    /// The day's crate directory, which holds its bundled resources.
+    pub dir: &'static str,
+    pub solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    pub time: fn(&str, &[Part], Duration) -> Result<DayTimings, ParseError>,
+    pub watch: fn(&str, Part, &mut dyn Observer) -> Result<Answer, ParseError>,
+    /// Makes a random input from a seed and a size.
+    pub generate: fn(u64, usize) -> String,
+    /// Answers a part while reading the input a line at a time, for days that can.
+    pub stream: Option<StreamFn>,
+    /// Answers a part with puzzle constants other than the real input's, for days that have any.
+    pub configured: Option<Configured>,
+    /// Parts that take minutes or more on the real input, which timing skips unless asked not to.
+    pub slow_parts: &'static [Part],
+}
+
+macro_rules! day {
+    ($number:expr, $solution:path, $dir:literal) => {
+        day!(@ $number, $solution, $dir, [], None, None)
+    };
+    ($number:expr, $solution:path, $dir:literal, slow = [$($slow:expr),*]) => {
+        day!(@ $number, $solution, $dir, [$($slow),*], None, None)
+    };
+    ($number:expr, $solution:path, $dir:literal, stream) => {
+        day!(@ $number, $solution, $dir, [], Some(
+            <$solution as common::solution::StreamSolution>::solve_stream
+        ), None)
+    };
+    ($number:expr, $solution:path, $dir:literal, stream, config) => {
+        day!(@ $number, $solution, $dir, [], Some(
+            <$solution as common::solution::StreamSolution>::solve_stream
+        ), Some(configured::<$solution>()))
+    };
+    ($number:expr, $solution:path, $dir:literal, config) => {
+        day!(@ $number, $solution, $dir, [], None, Some(configured::<$solution>()))
+    };
+    ($number:expr, $solution:path, $dir:literal, slow = [$($slow:expr),*], config) => {
+        day!(@ $number, $solution, $dir, [$($slow),*], None, Some(configured::<$solution>()))
+    };
+    (@ $number:expr, $solution:path, $dir:literal, [$($slow:expr),*], $stream:expr, $configured:expr) => {
+        Day {
+            number: $number,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", $dir),
+            solve: solve::<$solution>,
+            time: time_day::<$solution>,
+            watch: watch::<$solution>,
+            generate: generate::<$solution>,
+            stream: $stream,
+            configured: $configured,
+            slow_parts: &[$($slow),*],
+        }
+    };
+}
+
+const fn configured<S: ConfiguredSolution>() -> Configured {
+    Configured {
+        solve: |input, part, settings| {
+            let config = configure::<S>(settings)?;
+            return Ok(solve_with::<S>(input, part, &config)?);
+        },
+        watch: |input, part, settings, observer| {
+            let config = configure::<S>(settings)?;
+            return Ok(watch_with::<S>(input, part, &config, observer)?);
+        },
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day!(1, day_01::Day01, "day-01", stream),
+    day!(2, day_02::Day02, "day-02", stream, config),
+    day!(3, day_03::Day03, "day-03", stream, config),
+    day!(4, day_04::Day04, "day-04", stream),
+    day!(5, day_05::Day05, "day-05"),
+    day!(6, day_06::Day06, "day-06", stream),
+    day!(7, day_07::Day07, "day-07", config),
+    day!(8, day_08::Day08, "day-08"),
+    day!(9, day_09::Day09, "day-09"),
+    day!(10, day_10::Day10, "day-10", stream),
+    day!(11, day_11::Day11, "day-11"),
+    day!(12, day_12::Day12, "day-12"),
+    day!(13, day_13::Day13, "day-13"),
+    day!(14, day_14::Day14, "day-14", config),
+    day!(15, day_15::Day15, "day-15", config),
+    day!(16, day_16::Day16, "day-16", slow = [Part::Two], config),
+    day!(17, day_17::Day17, "day-17"),
+    day!(18, day_18::Day18, "day-18"),
+    day!(19, day_19::Day19, "day-19", slow = [Part::Two], config),
+    day!(20, day_20::Day20, "day-20", config),
+    day!(21, day_21::Day21, "day-21"),
+    day!(22, day_22::Day22, "day-22"),
+    day!(23, day_23::Day23, "day-23"),
+    day!(24, day_24::Day24, "day-24"),
+    day!(25, day_25::Day25, "day-25", stream),
+];
+
+impl Day {
+    /// Answers a part with the day's puzzle constants taken from `settings`, which must be empty
+    /// for days without any.
+    pub fn solve_with(
+        &self,
+        input: &str,
+        part: Part,
+        settings: &Settings,
+    ) -> Result<Answer, SolveError> {
+        match &self.configured {
+            Some(configured) => (configured.solve)(input, part, settings),
+            None => {
+                self.check_unconfigured(settings)?;
+                Ok((self.solve)(input, part)?)
+            }
+        }
+    }
+
+    /// Like `solve_with`, with `observer` watching the simulation step by step.
+    pub fn watch_with(
+        &self,
+        input: &str,
+        part: Part,
+        settings: &Settings,
+        observer: &mut dyn Observer,
+    ) -> Result<Answer, SolveError> {
+        match &self.configured {
+            Some(configured) => (configured.watch)(input, part, settings, observer),
+            None => {
+                self.check_unconfigured(settings)?;
+                Ok((self.watch)(input, part, observer)?)
+            }
+        }
+    }
+
+    fn check_unconfigured(&self, settings: &Settings) -> Result<(), SolveError> {
+        match settings.values.keys().next() {
+            Some(name) => Err(SolveError::Config(format!(
+                "day {} has no settings, but was given {}",
+                self.number, name
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{read_input, InputSource};
+
+    #[test]
+    fn examples_saved_with_crlf_and_a_final_newline_give_the_same_answers() {
+        for day in &DAYS {
+            let example = read_input(day.dir, &InputSource::Example).unwrap();
+            let crlf = format!("{}\r\n", example.replace('\n', "\r\n"));
+            assert_eq!(
+                (day.solve)(&crlf, Part::One),
+                (day.solve)(&example, Part::One),
+                "day {}",
+                day.number
+            );
+        }
+    }
+
+    #[test]
+    fn configured_days_accept_their_own_defaults() {
+        for day in DAYS.iter().filter(|day| day.configured.is_some()) {
+            for example in [false, true] {
+                let settings = Settings {
+                    example,
+                    ..Settings::default()
+                };
+                let input = read_input(day.dir, &InputSource::Example).unwrap();
+                assert!(
+                    day.solve_with(&input, Part::One, &settings).is_ok(),
+                    "day {}",
+                    day.number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn streaming_gives_the_same_answers_as_reading_everything() {
+        for day in DAYS.iter().filter(|day| day.stream.is_some()) {
+            for source in [InputSource::Example, InputSource::Bundled] {
+                let input = read_input(day.dir, &source).unwrap();
+                for part in Part::ALL {
+                    let streamed = (day.stream.unwrap())(&mut input.as_bytes(), part).unwrap();
+                    assert_eq!(
+                        Ok(streamed),
+                        (day.solve)(&input, part),
+                        "day {} part {} on {:?}",
+                        day.number,
+                        part.number(),
+                        source
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,590 @@
+mod bench;
+mod config;
+mod days;
+mod pool;
+mod verify;
+mod visualize;
+
+use bench::{day_key, read_baseline, write_baseline, Baseline, HumanDuration};
+use clap::{value_parser, Args, Parser, Subcommand};
+use common::{
+    answer::Answer,
+    input::{open_input, read_input, InputSource},
+    solution::{print_answer, OutputFormat, Part},
+};
+use config::{parse_setting, read_config, Settings};
+use days::{get_day, Day, DAYS};
+use pool::{parse_duration, run_parts, HumanBytes, Outcome, PartRun};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant},
+};
+use verify::{check, part_key, read_answers, write_answers, Verdict};
+use visualize::{FrameOptions, Output};
+
+const DEFAULT_BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench-baseline.toml");
+const DEFAULT_CONFIG: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day's puzzle, or every day's with --all
+    Run(RunArgs),
+    /// Time each day's parse and parts, and compare them against a saved baseline
+    Bench(BenchArgs),
+    /// Check every day's answers against the ones recorded in its resources/answers.toml
+    Verify(VerifyArgs),
+    /// Watch a day's simulation step by step, in the terminal or written out as images
+    Visualize(VisualizeArgs),
+    /// Print a random input for a day, in the same format as its real input
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve
+    #[arg(
+        long,
+        value_parser = value_parser!(u8).range(1..=25),
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    day: Option<u8>,
+
+    /// Only solve this part, instead of both
+    #[arg(long, value_parser = value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input to use instead of the day's bundled input, or - for stdin
+    #[arg(long, conflicts_with_all = ["all", "example"])]
+    input: Option<String>,
+
+    /// Use the puzzle description's example instead of the bundled input
+    #[arg(long)]
+    example: bool,
+
+    /// Solve every day and print a table of the answers
+    #[arg(long, conflicts_with = "set")]
+    all: bool,
+
+    /// Print answers as text, or as one JSON record per answer
+    #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+    format: String,
+
+    /// With --all, how many parts to solve at once (defaults to the number of CPUs)
+    #[arg(long, requires = "all", value_parser = value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+
+    /// With --all, give up on any part that takes longer than this, such as 30s or 500ms
+    #[arg(long, requires = "all", value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Read the input a line at a time instead of all at once, for days that can, with their default settings
+    #[arg(long, conflicts_with_all = ["all", "config", "set"])]
+    stream: bool,
+
+    /// Log each search's progress every second, and what it expanded and pruned once it's done
+    #[arg(long, conflicts_with = "all")]
+    trace: bool,
+
+    #[command(flatten)]
+    settings: SettingsArgs,
+}
+
+/// Where the puzzle constants that the input doesn't give come from, for the days that have any.
+#[derive(Args)]
+struct SettingsArgs {
+    /// File of puzzle constants with a table for each day, like [day-15] (defaults to the
+    /// workspace's aoc.toml)
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Set one of the day's puzzle constants over the config file's, such as target_row=10
+    #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_setting)]
+    set: Vec<(String, toml::Value)>,
+}
+
+impl SettingsArgs {
+    /// Collects the day's settings, with the example's constants as the defaults when solving it.
+    fn for_day(&self, day: u8, source: &InputSource) -> Settings {
+        let file = match &self.config {
+            Some(path) => read_config(path),
+            None if Path::new(DEFAULT_CONFIG).exists() => read_config(Path::new(DEFAULT_CONFIG)),
+            None => Ok(toml::Table::new()),
+        };
+        let file = file.unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+        let example = *source == InputSource::Example;
+        return Settings::for_day(&file, day, example, &self.set);
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only time these days, instead of all of them
+    #[arg(long, value_parser = value_parser!(u8).range(1..=25))]
+    day: Vec<u8>,
+
+    /// How long to spend repeating each stage, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    budget_ms: u64,
+
+    /// Baseline file to compare against and save to
+    #[arg(long, default_value = DEFAULT_BASELINE)]
+    baseline: PathBuf,
+
+    /// Save these timings as the new baseline
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// How many percent slower than the baseline a stage must be to count as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Also time parts that take minutes or more on the real input
+    #[arg(long)]
+    include_slow: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify these days, instead of all of them
+    #[arg(long, value_parser = value_parser!(u8).range(1..=25))]
+    day: Vec<u8>,
+
+    /// Record the current answers as the correct ones
+    #[arg(long)]
+    record: bool,
+
+    /// Also verify parts that take minutes or more on the real input
+    #[arg(long)]
+    include_slow: bool,
+}
+
+#[derive(Args)]
+struct VisualizeArgs {
+    /// The day to watch
+    #[arg(long, value_parser = value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to watch
+    #[arg(long, default_value_t = 1, value_parser = value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Puzzle input to use instead of the day's bundled input, or - for stdin
+    #[arg(long, conflicts_with = "example")]
+    input: Option<String>,
+
+    /// Use the puzzle description's example instead of the bundled input
+    #[arg(long)]
+    example: bool,
+
+    /// Only draw every this many steps
+    #[arg(long, default_value_t = 1, value_parser = value_parser!(u64).range(1..))]
+    every: u64,
+
+    /// How many frames to show each second
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Write the frames to an animated .gif, or to numbered .png or .ppm images, instead of playing them
+    #[arg(long)]
+    out: Option<PathBuf>,
+
+    /// How many pixels across each tile is in written images
+    #[arg(long, default_value_t = 4, value_parser = value_parser!(u64).range(1..=64))]
+    scale: u64,
+
+    #[command(flatten)]
+    settings: SettingsArgs,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// The day to make an input for
+    #[arg(long, value_parser = value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The same seed and size always make the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Roughly how many lines or items the input has
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Visualize(args) => visualize(args),
+        Command::Gen(args) => gen(args),
+    }
+}
+
+fn run(args: RunArgs) {
+    if args.trace {
+        tracing_subscriber::fmt()
+            .with_writer(io::stderr)
+            .with_target(false)
+            .init();
+    }
+    let parts = match args.part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let source = match (&args.input, args.example) {
+        (Some(arg), _) => InputSource::from_arg(arg),
+        (None, true) => InputSource::Example,
+        (None, false) => InputSource::Bundled,
+    };
+    let format = OutputFormat::from_name(&args.format).unwrap();
+    if args.all {
+        let jobs = args.jobs.map_or_else(
+            || thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+            |jobs| jobs as usize,
+        );
+        let days = DAYS.iter().map(|day| day.number).collect::<Vec<u8>>();
+        let config = args.settings.config.as_deref();
+        let runs =
+            run_parts(&days, &parts, &source, config, jobs, args.timeout).unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            });
+        match format {
+            OutputFormat::Text => print_run_report(&runs),
+            OutputFormat::Json => print_run_records(&runs),
+        }
+        if runs
+            .iter()
+            .any(|run| !matches!(run.outcome, Outcome::Answered(_)))
+        {
+            process::exit(1);
+        }
+        return;
+    }
+    let day = get_day(args.day.unwrap()).unwrap();
+    if args.stream {
+        run_streamed(day, &parts, &source, format);
+        return;
+    }
+    let settings = args.settings.for_day(day.number, &source);
+    let contents = match read_input(day.dir, &source) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+    for part in parts {
+        let start = Instant::now();
+        match day.solve_with(&contents, part, &settings) {
+            Ok(answer) => print_answer(format, day.number, part, &answer, start.elapsed()),
+            Err(err) => {
+                eprintln!("{}", err.report(&contents));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Answers each part straight from the input as it's read, opening it afresh for each part.
+fn run_streamed(day: &Day, parts: &[Part], source: &InputSource, format: OutputFormat) {
+    let Some(stream) = day.stream else {
+        eprintln!("error: day {} can't be streamed", day.number);
+        process::exit(1);
+    };
+    if *source == InputSource::Stdin && parts.len() > 1 {
+        eprintln!("error: stdin can only be streamed once, so pick a --part");
+        process::exit(1);
+    }
+    for &part in parts {
+        let start = Instant::now();
+        let answer = open_input(day.dir, source)
+            .map_err(|err| err.to_string())
+            .and_then(|mut reader| stream(&mut reader, part).map_err(|err| err.to_string()));
+        match answer {
+            Ok(answer) => print_answer(format, day.number, part, &answer, start.elapsed()),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Prints each part's answer along with how long it took and the most memory it used.
+fn print_run_report(runs: &[PartRun]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  Answer",
+        "Day", "Part", "Time", "Memory"
+    );
+    for run in runs {
+        let answer = match &run.outcome {
+            Outcome::Answered(answer) => answer.to_string(),
+            Outcome::Failed(message) => format!("error: {}", message),
+            Outcome::TimedOut => "timed out".to_owned(),
+        };
+        let peak_memory = run
+            .peak_memory
+            .map_or_else(|| "-".to_owned(), |bytes| HumanBytes(bytes).to_string());
+        let mut lines = answer.lines();
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {}",
+            run.day,
+            run.part.number(),
+            HumanDuration(run.wall_time),
+            peak_memory,
+            lines.next().unwrap_or_default()
+        );
+        for line in lines {
+            println!("{:>3}  {:>4}  {:>10}  {:>10}  {}", "", "", "", "", line);
+        }
+    }
+}
+
+fn print_run_records(runs: &[PartRun]) {
+    for run in runs {
+        match &run.outcome {
+            Outcome::Answered(answer) => {
+                print_answer(OutputFormat::Json, run.day, run.part, answer, run.wall_time)
+            }
+            Outcome::Failed(message) => {
+                eprintln!(
+                    "error: day {} part {}: {}",
+                    run.day,
+                    run.part.number(),
+                    message
+                )
+            }
+            Outcome::TimedOut => eprintln!(
+                "error: day {} part {}: timed out after {}",
+                run.day,
+                run.part.number(),
+                HumanDuration(run.wall_time)
+            ),
+        }
+    }
+}
+
+/// Prints one row of the answer table, lining up any further lines of the answer under the first.
+fn print_answer_row(day: &Day, part: Part, answer: &str) {
+    let mut lines = answer.lines();
+    println!(
+        "{:>3}  {:>4}  {}",
+        day.number,
+        part.number(),
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        println!("{:>3}  {:>4}  {}", "", "", line);
+    }
+}
+
+fn bench(args: BenchArgs) {
+    let baseline = if args.baseline.exists() {
+        match read_baseline(&args.baseline) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            }
+        }
+    } else {
+        Baseline::new()
+    };
+    let budget = Duration::from_millis(args.budget_ms);
+    let mut new_baseline = baseline.clone();
+    let mut regressions = 0;
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>7}",
+        "Day", "Stage", "Time", "Baseline", "Change"
+    );
+    for day in DAYS.iter() {
+        if !args.day.is_empty() && !args.day.contains(&day.number) {
+            continue;
+        }
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|part| args.include_slow || !day.slow_parts.contains(part))
+            .collect::<Vec<Part>>();
+        let contents = read_input(day.dir, &InputSource::Bundled).unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+        let timings = (day.time)(&contents, &parts, budget).unwrap_or_else(|err| {
+            eprintln!("{}", err.report(&contents));
+            process::exit(1);
+        });
+        let key = day_key(day.number);
+        let saved = baseline.get(&key);
+        for timing in timings {
+            let saved_time = saved
+                .and_then(|stages| stages.get(&timing.stage))
+                .map(|&nanos| Duration::from_nanos(nanos));
+            let (baseline_column, change_column, flag) = match saved_time {
+                Some(saved_time) => {
+                    let change =
+                        (timing.time.as_secs_f64() / saved_time.as_secs_f64() - 1.0) * 100.0;
+                    let regressed = change > args.threshold;
+                    if regressed {
+                        regressions += 1;
+                    }
+                    (
+                        HumanDuration(saved_time).to_string(),
+                        format!("{:+.0}%", change),
+                        if regressed { "  regressed" } else { "" },
+                    )
+                }
+                None => ("-".to_owned(), "-".to_owned(), ""),
+            };
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>7}{}",
+                day.number,
+                timing.stage,
+                HumanDuration(timing.time),
+                baseline_column,
+                change_column,
+                flag
+            );
+            new_baseline
+                .entry(key.clone())
+                .or_default()
+                .insert(timing.stage, timing.time.as_nanos() as u64);
+        }
+    }
+    if args.save_baseline {
+        if let Err(err) = write_baseline(&args.baseline, &new_baseline) {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+        println!("Saved baseline to {}", args.baseline.display());
+    }
+    if regressions > 0 {
+        eprintln!(
+            "{} stage(s) regressed by more than {}%",
+            regressions, args.threshold
+        );
+        process::exit(1);
+    }
+}
+
+fn verify(args: VerifyArgs) {
+    let mut failures = 0;
+    println!("{:>3}  {:>4}  Result", "Day", "Part");
+    for day in DAYS.iter() {
+        if !args.day.is_empty() && !args.day.contains(&day.number) {
+            continue;
+        }
+        let mut answers = read_answers(day.dir).unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+        let contents = read_input(day.dir, &InputSource::Bundled).unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+        for part in Part::ALL {
+            if !args.include_slow && day.slow_parts.contains(&part) {
+                print_answer_row(day, part, "skipped (slow)");
+                continue;
+            }
+            let answer = (day.solve)(&contents, part).unwrap_or_else(|err| {
+                eprintln!("{}", err.report(&contents));
+                process::exit(1);
+            });
+            let result = match check(&answers, part.number(), &answer) {
+                Verdict::Pass => "pass".to_owned(),
+                Verdict::Missing if answer == Answer::None => continue,
+                Verdict::Missing => "missing".to_owned(),
+                Verdict::Fail { expected } => {
+                    failures += 1;
+                    format!("FAIL\nexpected: {}\n     got: {}", expected, answer)
+                }
+            };
+            print_answer_row(day, part, &result);
+            answers.insert(part_key(part.number()), answer);
+        }
+        if args.record {
+            if let Err(err) = write_answers(day.dir, &answers) {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+    if args.record {
+        println!("Recorded the current answers");
+    } else if failures > 0 {
+        eprintln!("{} part(s) failed", failures);
+        process::exit(1);
+    }
+}
+
+fn visualize(args: VisualizeArgs) {
+    let day = get_day(args.day).unwrap();
+    let part = Part::from_number(args.part).unwrap();
+    let source = match (&args.input, args.example) {
+        (Some(arg), _) => InputSource::from_arg(arg),
+        (None, true) => InputSource::Example,
+        (None, false) => InputSource::Bundled,
+    };
+    let output = match args.out {
+        Some(path) => Output::from_path(path).unwrap_or_else(|| {
+            eprintln!("error: --out must end in .gif, .png or .ppm");
+            process::exit(2);
+        }),
+        None => Output::Terminal,
+    };
+    if args.fps.is_nan() || args.fps <= 0.0 {
+        eprintln!("error: --fps must be more than 0");
+        process::exit(2);
+    }
+    let options = FrameOptions {
+        every: args.every as usize,
+        fps: args.fps,
+        scale: args.scale as usize,
+    };
+    let settings = args.settings.for_day(day.number, &source);
+    let contents = read_input(day.dir, &source).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let start = Instant::now();
+    let (answer, frames) = visualize::visualize(day, &contents, part, &settings, &output, &options)
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        });
+    print_answer(
+        OutputFormat::Text,
+        day.number,
+        part,
+        &answer,
+        start.elapsed(),
+    );
+    if frames == 0 {
+        eprintln!("Day {} has no step-by-step simulation to draw", day.number);
+    } else if !matches!(output, Output::Terminal) {
+        eprintln!("Drew {} frame(s)", frames);
+    }
+}
+
+/// Prints the input without a newline at the end, like the bundled inputs, so it can be piped
+/// straight into `aoc run --input -`.
+fn gen(args: GenArgs) {
+    let day = get_day(args.day).unwrap();
+    print!("{}", (day.generate)(args.seed, args.size));
+}
@@ -0,0 +1,278 @@
+use common::{answer::Answer, input::InputSource, solution::Part};
+use std::{
+    collections::VecDeque,
+    env, fmt, io,
+    io::Read,
+    path::Path,
+    process::{Child, Command, Stdio},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How long a worker sleeps between checks on a part that hasn't finished yet.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// One part of one day, solved in a process of its own.
+pub struct PartRun {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub wall_time: Duration,
+    /// The largest resident set size of the part's process, in bytes, where the platform reports it.
+    pub peak_memory: Option<u64>,
+}
+
+pub enum Outcome {
+    Answered(Answer),
+    Failed(String),
+    TimedOut,
+}
+
+/// How a part's process ended.
+struct Exit {
+    success: bool,
+    peak_memory: Option<u64>,
+}
+
+/// Solves every part of every day on `jobs` worker threads, each part in a child `aoc run` process
+/// that's killed if it takes longer than `timeout`. Returns the runs in day and part order.
+pub fn run_parts(
+    days: &[u8],
+    parts: &[Part],
+    source: &InputSource,
+    config: Option<&Path>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> io::Result<Vec<PartRun>> {
+    let exe = env::current_exe()?;
+    let queue = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect::<VecDeque<(u8, Part)>>();
+    let total = queue.len();
+    let queue = Mutex::new(queue);
+    let runs = Mutex::new(Vec::with_capacity(total));
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().pop_front();
+                let Some((day, part)) = next else {
+                    break;
+                };
+                let mut command = Command::new(&exe);
+                command.args(["run", "--format", "json"]);
+                command.args([
+                    "--day",
+                    &day.to_string(),
+                    "--part",
+                    &part.number().to_string(),
+                ]);
+                match source {
+                    InputSource::Example => {
+                        command.arg("--example");
+                    }
+                    InputSource::File(path) => {
+                        command.arg("--input").arg(path);
+                    }
+                    InputSource::Bundled | InputSource::Stdin => {}
+                }
+                if let Some(config) = config {
+                    command.arg("--config").arg(config);
+                }
+                let run = run_part(command, day, part, timeout);
+                let mut runs = runs.lock().unwrap();
+                runs.push(run);
+                eprintln!(
+                    "[{}/{}] day {} part {}",
+                    runs.len(),
+                    total,
+                    day,
+                    part.number()
+                );
+            });
+        }
+    });
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| (run.day, run.part.number()));
+    return Ok(runs);
+}
+
+fn run_part(mut command: Command, day: u8, part: Part, timeout: Option<Duration>) -> PartRun {
+    let start = Instant::now();
+    let failed = |message: String| PartRun {
+        day,
+        part,
+        outcome: Outcome::Failed(message),
+        wall_time: start.elapsed(),
+        peak_memory: None,
+    };
+    let mut child = match command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => return failed(err.to_string()),
+    };
+    // Drain both pipes as the part runs, so that a chatty part can't fill one and stall.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let mut timed_out = false;
+    let exit = loop {
+        match reap(&mut child, false) {
+            Ok(Some(exit)) => break Ok(exit),
+            Ok(None) => {}
+            Err(err) => break Err(err),
+        }
+        if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+            timed_out = true;
+            let _ = child.kill();
+            break reap(&mut child, true).map(|exit| exit.unwrap());
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    let wall_time = start.elapsed();
+    let stdout = stdout.join().unwrap();
+    let stderr = stderr.join().unwrap();
+    let exit = match exit {
+        Ok(exit) => exit,
+        Err(err) => return failed(err.to_string()),
+    };
+    let outcome = if timed_out {
+        Outcome::TimedOut
+    } else if !exit.success {
+        let message = stderr.lines().next().unwrap_or("exited unsuccessfully");
+        let message = message.strip_prefix("error: ").unwrap_or(message);
+        Outcome::Failed(message.to_owned())
+    } else {
+        match parse_record_answer(&stdout) {
+            Ok(answer) => Outcome::Answered(answer),
+            Err(err) => Outcome::Failed(format!("unreadable answer: {}", err)),
+        }
+    };
+    return PartRun {
+        day,
+        part,
+        outcome,
+        wall_time,
+        peak_memory: exit.peak_memory,
+    };
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut contents = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut contents);
+        }
+        contents
+    })
+}
+
+fn parse_record_answer(record: &str) -> serde_json::Result<Answer> {
+    #[derive(serde::Deserialize)]
+    struct Record {
+        answer: Answer,
+    }
+    return serde_json::from_str::<Record>(record).map(|record| record.answer);
+}
+
+/// Waits for the child to exit, or only checks whether it has unless `block` is set, collecting its
+/// peak memory use as it's reaped.
+#[cfg(unix)]
+fn reap(child: &mut Child, block: bool) -> io::Result<Option<Exit>> {
+    let mut status = 0;
+    // SAFETY: rusage is plain data that wait4 fills in.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    let flags = if block { 0 } else { libc::WNOHANG };
+    // SAFETY: both pointers are to live locals, and the child hasn't been reaped yet, so its pid
+    // can't have been reused.
+    let reaped = unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, flags, &mut usage) };
+    if reaped < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if reaped == 0 {
+        return Ok(None);
+    }
+    // Linux and the BSDs report ru_maxrss in kilobytes, but macOS reports it in bytes.
+    let unit = if cfg!(target_os = "macos") { 1 } else { 1024 };
+    return Ok(Some(Exit {
+        success: libc::WIFEXITED(status) && libc::WEXITSTATUS(status) == 0,
+        peak_memory: Some(usage.ru_maxrss as u64 * unit),
+    }));
+}
+
+#[cfg(not(unix))]
+fn reap(child: &mut Child, block: bool) -> io::Result<Option<Exit>> {
+    let status = if block {
+        Some(child.wait()?)
+    } else {
+        child.try_wait()?
+    };
+    return Ok(status.map(|status| Exit {
+        success: status.success(),
+        peak_memory: None,
+    }));
+}
+
+/// Reads a duration such as `30s`, `500ms` or `2m`, where a bare number is in seconds.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("expected a number of seconds, got {:?}", text))?;
+    let seconds = match unit.trim() {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        unit => return Err(format!("expected a unit of ms, s or m, got {:?}", unit)),
+    };
+    return Ok(Duration::from_secs_f64(seconds));
+}
+
+/// A size in bytes, printed in the largest binary unit that keeps it at least 1.
+pub struct HumanBytes(pub u64);
+
+impl fmt::Display for HumanBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes = self.0 as f64;
+        let text = if bytes < 1024.0 {
+            format!("{} B", self.0)
+        } else if bytes < 1024.0 * 1024.0 {
+            format!("{:.1} KiB", bytes / 1024.0)
+        } else if bytes < 1024.0 * 1024.0 * 1024.0 {
+            format!("{:.1} MiB", bytes / (1024.0 * 1024.0))
+        } else {
+            format!("{:.1} GiB", bytes / (1024.0 * 1024.0 * 1024.0))
+        };
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_read_with_or_without_units() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("3h").is_err());
+    }
+
+    #[test]
+    fn byte_sizes_are_printed_in_the_largest_fitting_unit() {
+        assert_eq!(HumanBytes(512).to_string(), "512 B");
+        assert_eq!(HumanBytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(HumanBytes(12 * 1024 * 1024).to_string(), "12.0 MiB");
+    }
+}
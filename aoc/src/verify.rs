@@ -0,0 +1,54 @@
+use common::answer::Answer;
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// Known answers for a day's bundled input, keyed by `part1` and `part2`.
+pub type Answers = BTreeMap<String, Answer>;
+
+/// How a part's answer compares to the one on record.
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+pub fn answers_path(day_dir: &str) -> PathBuf {
+    PathBuf::from(day_dir)
+        .join("resources")
+        .join("answers.toml")
+}
+
+pub fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+/// Reads a day's answers file, treating a day without one as having no answers on record.
+pub fn read_answers(day_dir: &str) -> Result<Answers, String> {
+    let path = answers_path(day_dir);
+    if !path.exists() {
+        return Ok(Answers::new());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    toml::from_str(&contents).map_err(|err| format!("invalid answers {}: {}", path.display(), err))
+}
+
+/// Writes a day's answers file, leaving out parts that have no answer.
+pub fn write_answers(day_dir: &str, answers: &Answers) -> Result<(), String> {
+    let path = answers_path(day_dir);
+    let answers = answers
+        .iter()
+        .filter(|(_, answer)| **answer != Answer::None)
+        .collect::<BTreeMap<&String, &Answer>>();
+    let contents = toml::to_string_pretty(&answers).map_err(|err| err.to_string())?;
+    fs::write(&path, contents).map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
+
+pub fn check(answers: &Answers, part: u8, answer: &Answer) -> Verdict {
+    match answers.get(&part_key(part)) {
+        Some(expected) if expected == answer => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.clone(),
+        },
+        None => Verdict::Missing,
+    }
+}
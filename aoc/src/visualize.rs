@@ -0,0 +1,203 @@
+use crate::{config::Settings, days::Day};
+use common::{
+    answer::Answer,
+    pos::MapPos,
+    solution::Part,
+    visualize::{animate, palette, Frame, Sampler},
+};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Where a simulation's frames go.
+pub enum Output {
+    /// Played in place in the terminal.
+    Terminal,
+    /// Written as one animated GIF.
+    Gif(PathBuf),
+    /// Written as numbered PNG images alongside the given path.
+    Png(PathBuf),
+    /// Written as numbered PPM images alongside the given path.
+    Ppm(PathBuf),
+}
+
+impl Output {
+    /// Picks the image format from a path's extension.
+    pub fn from_path(path: PathBuf) -> Option<Output> {
+        match path.extension()?.to_str()? {
+            "gif" => Some(Output::Gif(path)),
+            "png" => Some(Output::Png(path)),
+            "ppm" => Some(Output::Ppm(path)),
+            _ => None,
+        }
+    }
+}
+
+/// How frames are drawn: which steps to keep, how fast to show them, and how big to make each tile.
+pub struct FrameOptions {
+    pub every: usize,
+    pub fps: f64,
+    pub scale: usize,
+}
+
+/// Watches a day's part, sending the frames it draws to `output`. Returns the part's answer and how many
+/// frames were drawn, which is none for days that don't simulate anything step by step.
+pub fn visualize(
+    day: &Day,
+    input: &str,
+    part: Part,
+    settings: &Settings,
+    output: &Output,
+    options: &FrameOptions,
+) -> Result<(Answer, usize), String> {
+    let delay = Duration::from_secs_f64(1.0 / options.fps);
+    match output {
+        Output::Terminal => {
+            let mut observer = animate(options.every, delay);
+            let answer = day
+                .watch_with(input, part, settings, &mut observer)
+                .map_err(|err| err.report(input))?;
+            Ok((answer, observer.steps() / options.every.max(1)))
+        }
+        Output::Gif(path) => write_gif(day, input, part, settings, path, options, delay),
+        Output::Png(path) | Output::Ppm(path) => {
+            let mut frames = 0;
+            let mut error = None;
+            let mut observer = Sampler::new(options.every, |step, frame: Frame| {
+                if error.is_some() {
+                    return;
+                }
+                frames += 1;
+                let path = numbered_path(path, step);
+                let written = match output {
+                    Output::Png(_) => write_png(&path, &frame, options.scale),
+                    _ => fs::write(&path, frame.to_ppm(options.scale, palette))
+                        .map_err(|err| err.to_string()),
+                };
+                error = written
+                    .err()
+                    .map(|err| format!("could not write {}: {}", path.display(), err));
+            });
+            let answer = day
+                .watch_with(input, part, settings, &mut observer)
+                .map_err(|err| err.report(input))?;
+            match error {
+                Some(err) => Err(err),
+                None => Ok((answer, frames)),
+            }
+        }
+    }
+}
+
+/// Turns `out.png` into `out-00042.png` for step 42.
+fn numbered_path(path: &Path, step: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}-{:05}.{}", stem, step, extension))
+}
+
+fn write_png(path: &Path, frame: &Frame, scale: usize) -> Result<(), String> {
+    let (min, max) = frame.bounds();
+    let width = ((max.0 - min.0 + 1) as usize * scale) as u32;
+    let height = ((max.1 - min.1 + 1) as usize * scale) as u32;
+    let file = File::create(path).map_err(|err| err.to_string())?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let pixels = frame.pixels(frame.bounds(), scale, |tile| palette(tile.unwrap_or(' ')));
+    let mut writer = encoder.write_header().map_err(|err| err.to_string())?;
+    writer
+        .write_image_data(&pixels.concat())
+        .map_err(|err| err.to_string())
+}
+
+/// Writes every kept frame into one GIF. Frames can change size as the simulation runs, so a first run
+/// finds the area and the tiles every frame covers, and a second run draws each frame onto that area.
+fn write_gif(
+    day: &Day,
+    input: &str,
+    part: Part,
+    settings: &Settings,
+    path: &Path,
+    options: &FrameOptions,
+    delay: Duration,
+) -> Result<(Answer, usize), String> {
+    let mut canvas: Option<(MapPos, MapPos)> = None;
+    let mut tiles = BTreeMap::from([(' ', 0)]);
+    let mut observer = Sampler::new(options.every, |_, frame: Frame| {
+        let (min, max) = frame.bounds();
+        canvas = Some(match canvas {
+            Some((canvas_min, canvas_max)) => (
+                (canvas_min.0.min(min.0), canvas_min.1.min(min.1)),
+                (canvas_max.0.max(max.0), canvas_max.1.max(max.1)),
+            ),
+            None => (min, max),
+        });
+        for tile in frame.tiles() {
+            let next_index = tiles.len();
+            tiles.entry(tile).or_insert(next_index);
+        }
+    });
+    let answer = day
+        .watch_with(input, part, settings, &mut observer)
+        .map_err(|err| err.report(input))?;
+    let Some(canvas) = canvas else {
+        return Ok((answer, 0));
+    };
+    if tiles.len() > 256 {
+        return Err(format!(
+            "frames use {} different tiles, but a GIF can only have 256 colours",
+            tiles.len()
+        ));
+    }
+
+    let (min, max) = canvas;
+    let width = (max.0 - min.0 + 1) as usize * options.scale;
+    let height = (max.1 - min.1 + 1) as usize * options.scale;
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(format!("{}x{} is too big for a GIF", width, height));
+    }
+    let mut colours = vec![[0; 3]; tiles.len()];
+    for (&tile, &index) in &tiles {
+        colours[index] = palette(tile);
+    }
+    let file =
+        File::create(path).map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    let mut encoder = gif::Encoder::new(
+        BufWriter::new(file),
+        width as u16,
+        height as u16,
+        &colours.concat(),
+    )
+    .map_err(|err| err.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|err| err.to_string())?;
+    // GIF delays are counted in hundredths of a second.
+    let delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+    let mut frames = 0;
+    let mut error = None;
+    let mut observer = Sampler::new(options.every, |_, frame: Frame| {
+        if error.is_some() {
+            return;
+        }
+        frames += 1;
+        let pixels = frame.pixels(canvas, options.scale, |tile| {
+            tiles[&tile.unwrap_or(' ')] as u8
+        });
+        let mut gif_frame =
+            gif::Frame::from_indexed_pixels(width as u16, height as u16, pixels, None);
+        gif_frame.delay = delay;
+        error = encoder.write_frame(&gif_frame).err();
+    });
+    day.watch_with(input, part, settings, &mut observer)
+        .map_err(|err| err.report(input))?;
+    match error {
+        Some(err) => Err(format!("could not write {}: {}", path.display(), err)),
+        None => Ok((answer, frames)),
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// A grid of lit and unlit pixels, such as day 10's CRT, whose letters are read by eye.
+    Bitmap(Vec<Vec<bool>>),
+    /// The puzzle has no such part, as with the second half of day 25.
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{}", val),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Bitmap(rows) => write!(f, "{}", bitmap_rows(rows).join("\n")),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+/// Draws each row of a bitmap with `#` for lit pixels and `.` for unlit ones.
+fn bitmap_rows(rows: &[Vec<bool>]) -> Vec<String> {
+    rows.iter()
+        .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+        .collect()
+}
+
+/// Integers and text serialize as themselves, bitmaps as their drawn rows, and missing answers as null.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(val) => serializer.serialize_i64(*val),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Bitmap(rows) => bitmap_rows(rows).serialize(serializer),
+            Answer::None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+/// Reads answers back in the shapes they serialize to.
+struct AnswerVisitor;
+
+impl<'de> Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer, a string, or a list of bitmap rows")
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Answer, E> {
+        Ok(Answer::Int(val))
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Answer, E> {
+        i64::try_from(val)
+            .map(Answer::Int)
+            .map_err(|_| E::custom("integer answer too large"))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Answer, E> {
+        Ok(Answer::from(text))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+        let mut rows = Vec::new();
+        while let Some(row) = seq.next_element::<String>()? {
+            rows.push(row.chars().map(|c| c == '#').collect());
+        }
+        Ok(Answer::Bitmap(rows))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(val: $int) -> Self {
+                    Answer::Int(val.into())
+                }
+            }
+        )*
+    };
+}
+
+/// Integers that might not fit in an `i64` are given as text when they don't, rather than wrapping.
+macro_rules! impl_from_wide_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(val: $int) -> Self {
+                    match i64::try_from(val) {
+                        Ok(val) => Answer::Int(val),
+                        Err(_) => Answer::Text(val.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_wide_int!(i128, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(rows: Vec<Vec<bool>>) -> Self {
+        Answer::Bitmap(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_round_trip_through_their_serialized_form() {
+        let answers = [
+            Answer::Int(-42),
+            Answer::from("CMZ"),
+            Answer::Bitmap(vec![vec![true, false], vec![false, true]]),
+            Answer::None,
+        ];
+        for answer in answers {
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn integers_too_big_for_an_int_are_given_as_text() {
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Int(i64::MAX));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_owned())
+        );
+        assert_eq!(Answer::from(i128::MIN), Answer::Text(i128::MIN.to_string()));
+        assert_eq!(Answer::from(-5_i128), Answer::Int(-5));
+    }
+
+    #[test]
+    fn bitmaps_serialize_as_drawn_rows() {
+        let answer = Answer::Bitmap(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(serde_json::to_string(&answer).unwrap(), r##"["#.",".#"]"##);
+    }
+}
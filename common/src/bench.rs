@@ -0,0 +1,32 @@
+/// Defines a criterion bench target that times a day's parse, part 1 and part 2 separately
+/// against its bundled input.
+///
+/// Parts that take too long to be worth sampling can be left out by listing the ones to time.
+#[macro_export]
+macro_rules! bench_day {
+    ($name:literal, $solution:ty) => {
+        $crate::bench_day!($name, $solution, [
+            $crate::solution::Part::One,
+            $crate::solution::Part::Two
+        ]);
+    };
+    ($name:literal, $solution:ty, [$($part:expr),*]) => {
+        fn bench(c: &mut criterion::Criterion) {
+            use $crate::solution::{Part, Solution};
+            let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/input.txt"));
+            let parsed = <$solution>::parse(input).expect("Bundled input should parse");
+            let mut group = c.benchmark_group($name);
+            group.bench_function("parse", |b| b.iter(|| <$solution>::parse(std::hint::black_box(input))));
+            for part in [$($part),*] {
+                group.bench_function(format!("part{}", part.number()), |b| match part {
+                    Part::One => b.iter(|| <$solution>::part1(std::hint::black_box(&parsed))),
+                    Part::Two => b.iter(|| <$solution>::part2(std::hint::black_box(&parsed))),
+                });
+            }
+            group.finish();
+        }
+
+        criterion::criterion_group!(benches, bench);
+        criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,60 @@
+use crate::pos::{MapPos, MapPosComp};
+
+/// A direction on a map whose y axis grows downwards, as in the puzzle inputs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CardinalDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl CardinalDirection {
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
+    pub fn get_offset(&self) -> (i8, i8) {
+        match self {
+            CardinalDirection::North => (0, -1),
+            CardinalDirection::East => (1, 0),
+            CardinalDirection::South => (0, 1),
+            CardinalDirection::West => (-1, 0),
+        }
+    }
+
+    pub fn turn_left(&self) -> CardinalDirection {
+        match self {
+            CardinalDirection::North => CardinalDirection::West,
+            CardinalDirection::East => CardinalDirection::North,
+            CardinalDirection::South => CardinalDirection::East,
+            CardinalDirection::West => CardinalDirection::South,
+        }
+    }
+
+    pub fn turn_right(&self) -> CardinalDirection {
+        match self {
+            CardinalDirection::North => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::North,
+        }
+    }
+}
+
+pub trait CanAddCardinalDirection {
+    fn add(self, other: CardinalDirection) -> Self;
+}
+
+impl CanAddCardinalDirection for MapPos {
+    fn add(self, other: CardinalDirection) -> Self {
+        let offset = other.get_offset();
+        (
+            self.0 + offset.0 as MapPosComp,
+            self.1 + offset.1 as MapPosComp,
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use rand::SeedableRng;
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// Makes random puzzle inputs for a day, in the same format as its real input and always solvable.
+pub trait Generator {
+    /// Generates an input whose size grows with `size`, which roughly counts its lines or items.
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+}
+
+/// Generates the input for a seed, which always comes out the same for the same seed and size.
+pub fn generate<G: Generator>(seed: u64, size: usize) -> String {
+    G::generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Joins generated lines the way puzzle inputs are laid out: a line each, with no newline at the end.
+pub fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().collect::<Vec<String>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Numbers;
+
+    impl Generator for Numbers {
+        fn generate(rng: &mut StdRng, size: usize) -> String {
+            join_lines((0..size).map(|_| rng.gen_range(0..1000).to_string()))
+        }
+    }
+
+    #[test]
+    fn seeds_always_generate_the_same_input() {
+        assert_eq!(generate::<Numbers>(7, 5), generate::<Numbers>(7, 5));
+        assert_ne!(generate::<Numbers>(7, 5), generate::<Numbers>(8, 5));
+        assert_eq!(generate::<Numbers>(7, 5).lines().count(), 5);
+    }
+}
@@ -0,0 +1,465 @@
+use crate::{
+    input::{char_grid, lines},
+    parse::ParseError,
+    pos::{MapPos, MapPosComp},
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut, RangeInclusive},
+};
+
+/// Offsets to the four orthogonal neighbors of a position, in the same order as `CardinalDirection::ALL`.
+pub const NEIGHBORS_4: [MapPos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight positions surrounding a position, diagonals included, in reading order.
+pub const NEIGHBORS_8: [MapPos; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Storage<T> {
+    /// A cell for every position in the bounds, stored row by row.
+    Dense { width: usize, cells: Vec<T> },
+    /// Only the cells that have been set.
+    Sparse(HashMap<MapPos, T>),
+}
+
+/// A 2D map of cells indexed by `MapPos`, with the y axis growing downwards as in the puzzle inputs.
+///
+/// Dense grids have a cell at every position of a fixed area whose top left corner is the origin,
+/// which suits puzzles like day 8's forest. Sparse grids only hold the cells that have been set and
+/// grow their bounds to fit them, which suits puzzles like day 23's spreading elves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    storage: Storage<T>,
+    /// The inclusive top left and bottom right corners of the area the grid covers, if any.
+    bounds: Option<(MapPos, MapPos)>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a dense grid with every cell set to `fill`.
+    pub fn new_dense(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(width, vec![fill; width * height])
+    }
+
+    pub fn new_sparse() -> Grid<T> {
+        Grid {
+            storage: Storage::Sparse(HashMap::new()),
+            bounds: None,
+        }
+    }
+
+    /// Creates a dense grid from rows of cells, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows must all be the same length"
+        );
+        Grid::from_cells(width, rows.into_iter().flatten().collect())
+    }
+
+    fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        let bounds = if width == 0 || height == 0 {
+            None
+        } else {
+            Some(((0, 0), (width as MapPosComp - 1, height as MapPosComp - 1)))
+        };
+        Grid {
+            storage: Storage::Dense { width, cells },
+            bounds,
+        }
+    }
+
+    /// Parses a dense grid from a char map, where every char must be turned into a cell by `cell`.
+    ///
+    /// `map` must be a slice of `input`, so that errors can point at the offending char.
+    pub fn parse_dense(
+        input: &str,
+        map: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in lines(map) {
+            for (i, c) in line.char_indices() {
+                let token = &line[i..i + c.len_utf8()];
+                cells.push(
+                    cell(c).ok_or_else(|| ParseError::new(input, token, "unexpected map tile"))?,
+                );
+            }
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        format!("expected a row of {} tiles", width),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        return Ok(Grid::from_cells(width.unwrap_or(0), cells));
+    }
+
+    /// Parses a sparse grid from a char map, skipping `blank` chars and turning the rest into cells with `cell`.
+    ///
+    /// `map` must be a slice of `input`, so that errors can point at the offending char.
+    pub fn parse_sparse(
+        input: &str,
+        map: &str,
+        blank: char,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut grid = Grid::new_sparse();
+        for (pos, c, token) in char_grid(map) {
+            if c == blank {
+                continue;
+            }
+            let value =
+                cell(c).ok_or_else(|| ParseError::new(input, token, "unexpected map tile"))?;
+            grid.set(pos, value);
+        }
+        return Ok(grid);
+    }
+
+    pub fn is_dense(&self) -> bool {
+        matches!(self.storage, Storage::Dense { .. })
+    }
+
+    /// The inclusive top left and bottom right corners of the grid, or `None` if it covers no area.
+    pub fn bounds(&self) -> Option<(MapPos, MapPos)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.0 - min.0 + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.1 - min.1 + 1) as usize)
+    }
+
+    pub fn in_bounds(&self, pos: MapPos) -> bool {
+        match self.bounds {
+            Some((min, max)) => {
+                (min.0..=max.0).contains(&pos.0) && (min.1..=max.1).contains(&pos.1)
+            }
+            None => false,
+        }
+    }
+
+    fn index(&self, pos: MapPos) -> Option<usize> {
+        match &self.storage {
+            Storage::Dense { width, .. } if self.in_bounds(pos) => {
+                Some(pos.1 as usize * width + pos.0 as usize)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, pos: MapPos) -> Option<&T> {
+        match &self.storage {
+            Storage::Dense { cells, .. } => self.index(pos).map(|i| &cells[i]),
+            Storage::Sparse(cells) => cells.get(&pos),
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: MapPos) -> Option<&mut T> {
+        let index = self.index(pos);
+        match &mut self.storage {
+            Storage::Dense { cells, .. } => index.map(|i| &mut cells[i]),
+            Storage::Sparse(cells) => cells.get_mut(&pos),
+        }
+    }
+
+    /// Whether the grid has a cell at the position.
+    pub fn contains(&self, pos: MapPos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// Sets the cell at a position, growing a sparse grid's bounds to fit it.
+    ///
+    /// Panics if the position is outside a dense grid.
+    pub fn set(&mut self, pos: MapPos, value: T) {
+        let index = self.index(pos);
+        match &mut self.storage {
+            Storage::Dense { cells, .. } => {
+                let i = index.unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+                cells[i] = value;
+            }
+            Storage::Sparse(cells) => {
+                cells.insert(pos, value);
+                self.bounds = Some(match self.bounds {
+                    Some((min, max)) => (
+                        (min.0.min(pos.0), min.1.min(pos.1)),
+                        (max.0.max(pos.0), max.1.max(pos.1)),
+                    ),
+                    None => (pos, pos),
+                });
+            }
+        }
+    }
+
+    /// Removes the cell at a position of a sparse grid, leaving its bounds as they were.
+    ///
+    /// Panics if the grid is dense, since dense grids have a cell at every position.
+    pub fn remove(&mut self, pos: MapPos) -> Option<T> {
+        match &mut self.storage {
+            Storage::Dense { .. } => panic!("Cells can't be removed from a dense grid"),
+            Storage::Sparse(cells) => cells.remove(&pos),
+        }
+    }
+
+    /// Maps a position onto the grid as if its edges wrapped around, like a torus.
+    ///
+    /// Panics if the grid covers no area.
+    pub fn wrap(&self, pos: MapPos) -> MapPos {
+        let (min, _) = self.bounds.expect("Can't wrap around an empty grid");
+        (
+            (pos.0 - min.0).rem_euclid(self.width() as MapPosComp) + min.0,
+            (pos.1 - min.1).rem_euclid(self.height() as MapPosComp) + min.1,
+        )
+    }
+
+    pub fn get_wrapped(&self, pos: MapPos) -> Option<&T> {
+        self.get(self.wrap(pos))
+    }
+
+    /// The cells at the given offsets from a position, such as `NEIGHBORS_4`, skipping any that don't exist.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: MapPos,
+        offsets: &'a [MapPos],
+    ) -> impl Iterator<Item = (MapPos, &'a T)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let neighbor = (pos.0 + offset.0, pos.1 + offset.1);
+            self.get(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// Like `neighbors`, but with the grid's edges wrapping around.
+    pub fn wrapped_neighbors<'a>(
+        &'a self,
+        pos: MapPos,
+        offsets: &'a [MapPos],
+    ) -> impl Iterator<Item = (MapPos, &'a T)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let neighbor = self.wrap((pos.0 + offset.0, pos.1 + offset.1));
+            self.get(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// The corners of the grid, with an empty grid's bottom right corner above and left of its top left.
+    fn corners(&self) -> (MapPos, MapPos) {
+        self.bounds.unwrap_or(((0, 0), (-1, -1)))
+    }
+
+    fn x_range(&self) -> RangeInclusive<MapPosComp> {
+        let (min, max) = self.corners();
+        min.0..=max.0
+    }
+
+    fn y_range(&self) -> RangeInclusive<MapPosComp> {
+        let (min, max) = self.corners();
+        min.1..=max.1
+    }
+
+    /// The cells of row `y`, from left to right.
+    pub fn row(&self, y: MapPosComp) -> impl Iterator<Item = (MapPos, &T)> + '_ {
+        self.x_range()
+            .filter_map(move |x| self.get((x, y)).map(|cell| ((x, y), cell)))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: MapPosComp) -> impl Iterator<Item = (MapPos, &T)> + '_ {
+        self.y_range()
+            .filter_map(move |y| self.get((x, y)).map(|cell| ((x, y), cell)))
+    }
+
+    /// The cells met by stepping from a position by `step` until leaving the grid's bounds.
+    ///
+    /// The starting position itself isn't included.
+    pub fn ray(&self, from: MapPos, step: MapPos) -> impl Iterator<Item = (MapPos, &T)> + '_ {
+        std::iter::successors(Some(from), move |pos| {
+            Some((pos.0 + step.0, pos.1 + step.1))
+        })
+        .skip(1)
+        .take_while(|&pos| self.in_bounds(pos))
+        .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Every cell with its position. Dense grids go in reading order, sparse grids in no particular order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (MapPos, &T)> + '_> {
+        match &self.storage {
+            Storage::Dense { width, cells } => {
+                let width = *width;
+                Box::new(cells.iter().enumerate().map(move |(i, cell)| {
+                    (((i % width) as MapPosComp, (i / width) as MapPosComp), cell)
+                }))
+            }
+            Storage::Sparse(cells) => Box::new(cells.iter().map(|(pos, cell)| (*pos, cell))),
+        }
+    }
+
+    /// Builds a grid of the same shape and storage by transforming each cell.
+    pub fn map<U>(&self, mut f: impl FnMut(MapPos, &T) -> U) -> Grid<U> {
+        let storage = match &self.storage {
+            Storage::Dense { width, .. } => Storage::Dense {
+                width: *width,
+                cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+            },
+            Storage::Sparse(cells) => Storage::Sparse(
+                cells
+                    .iter()
+                    .map(|(pos, cell)| (*pos, f(*pos, cell)))
+                    .collect(),
+            ),
+        };
+        Grid {
+            storage,
+            bounds: self.bounds,
+        }
+    }
+
+    /// Draws the grid within its bounds a row per line, choosing a char for each position.
+    pub fn render(&self, mut tile: impl FnMut(Option<&T>) -> char) -> String {
+        self.y_range()
+            .map(|y| {
+                self.x_range()
+                    .map(|x| tile(self.get((x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// Panics if the grid has no cell at the position.
+impl<T> Index<MapPos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: MapPos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("No grid cell at {:?}", pos))
+    }
+}
+
+impl<T> IndexMut<MapPos> for Grid<T> {
+    fn index_mut(&mut self, pos: MapPos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("No grid cell at {:?}", pos))
+    }
+}
+
+/// Draws each cell with its own `Display`, and positions without a cell as `.`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, y) in self.y_range().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for x in self.x_range() {
+                match self.get((x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "123\n456";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse_dense(MAP, MAP, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_dense_char_maps() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn reports_bad_tiles_and_ragged_rows() {
+        let input = "12\n3x";
+        let err = Grid::parse_dense(input, input, |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+        let input = "12\n3";
+        let err = Grid::parse_dense(input, input, |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn neighbors_stop_at_the_edges_unless_wrapped() {
+        let grid = digits();
+        let cells = |neighbors: Vec<(MapPos, &u32)>| {
+            neighbors
+                .into_iter()
+                .map(|(_, &cell)| cell)
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(
+            cells(grid.neighbors((0, 0), &NEIGHBORS_4).collect()),
+            [2, 4]
+        );
+        assert_eq!(
+            cells(grid.neighbors((0, 0), &NEIGHBORS_8).collect()),
+            [2, 4, 5]
+        );
+        assert_eq!(
+            cells(grid.wrapped_neighbors((0, 0), &NEIGHBORS_4).collect()),
+            [4, 2, 4, 3]
+        );
+        assert_eq!(grid.wrap((-1, 5)), (2, 1));
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits();
+        let cells =
+            |line: Vec<(MapPos, &u32)>| line.into_iter().map(|(_, &c)| c).collect::<Vec<u32>>();
+        assert_eq!(cells(grid.row(1).collect()), [4, 5, 6]);
+        assert_eq!(cells(grid.column(2).collect()), [3, 6]);
+        assert_eq!(cells(grid.ray((0, 0), (1, 1)).collect()), [5]);
+        assert_eq!(cells(grid.ray((2, 1), (-1, 0)).collect()), [5, 4]);
+    }
+
+    #[test]
+    fn sparse_grids_grow_to_fit() {
+        let input = "#..\n..#";
+        let mut grid =
+            Grid::parse_sparse(input, input, '.', |c| (c == '#').then_some('#')).unwrap();
+        assert!(!grid.is_dense());
+        assert_eq!(grid.bounds(), Some(((0, 0), (2, 1))));
+        grid.set((-1, 2), '#');
+        grid.remove((2, 1));
+        assert_eq!(grid.bounds(), Some(((-1, 0), (2, 2))));
+        assert_eq!(grid.to_string(), ".#..\n....\n#...");
+    }
+}
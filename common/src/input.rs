@@ -0,0 +1,371 @@
+use crate::{
+    parse::ParseError,
+    pos::{MapPos, MapPosComp},
+};
+use std::{
+    fmt,
+    io::{self, BufRead},
+    path::PathBuf,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    fs,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `resources/input.txt`.
+    Bundled,
+    /// The puzzle description's example, bundled as `resources/example.txt`.
+    Example,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets a path given on the command line, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// Reads the arguments of a day's binary, which name at most one input: a path, `-` for stdin, or
+/// `--example`, defaulting to the bundled input.
+///
+/// Any other flag is handed to `flag` along with the rest of the arguments, so that it can take a
+/// value from them. Returns `None` if `flag` does, or if more than one input is named.
+pub fn parse_source_args(
+    args: &[String],
+    mut flag: impl FnMut(&str, &mut std::slice::Iter<String>) -> Option<()>,
+) -> Option<InputSource> {
+    let mut source = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let next_source = match arg.as_str() {
+            "--example" => InputSource::Example,
+            _ if arg.starts_with("--") => {
+                flag(arg, &mut args)?;
+                continue;
+            }
+            _ => InputSource::from_arg(arg),
+        };
+        if source.replace(next_source).is_some() {
+            return None;
+        }
+    }
+    return Some(source.unwrap_or(InputSource::Bundled));
+}
+
+/// An input that couldn't be read, along with where it was being read from.
+#[derive(Debug)]
+pub struct InputError {
+    pub location: String,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.location, self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+// Inputs are only read from files and stdin outside the WebAssembly build, which has neither.
+
+/// Reads a day's puzzle input from the given source, given that day's crate directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read_input(day_dir: &str, source: &InputSource) -> Result<String, InputError> {
+    let resources = Path::new(day_dir).join("resources");
+    match source {
+        InputSource::Bundled => read_file(&resources.join("input.txt")),
+        InputSource::Example => read_file(&resources.join("example.txt")),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut contents = String::new();
+            match io::stdin().read_to_string(&mut contents) {
+                Ok(_) => Ok(contents),
+                Err(error) => Err(InputError {
+                    location: "stdin".to_owned(),
+                    error,
+                }),
+            }
+        }
+    }
+}
+
+/// Opens a day's puzzle input for reading a little at a time, instead of all at once like `read_input`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open_input(day_dir: &str, source: &InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    let resources = Path::new(day_dir).join("resources");
+    let path = match source {
+        InputSource::Bundled => resources.join("input.txt"),
+        InputSource::Example => resources.join("example.txt"),
+        InputSource::File(path) => path.clone(),
+        InputSource::Stdin => return Ok(Box::new(io::stdin().lock())),
+    };
+    match fs::File::open(&path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(error) => Err(InputError {
+            location: path.display().to_string(),
+            error,
+        }),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| InputError {
+        location: path.display().to_string(),
+        error,
+    })
+}
+
+/// Splits an input into lines, dropping the `\r` of CRLF line endings and any blank lines at the end.
+///
+/// Each line is a slice of `input`, so errors can still point into it. An input with nothing in it is
+/// a single empty line, so days report it as whatever they expected to find.
+pub fn lines(input: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let end = match input.trim_end().len() {
+        0 => 0,
+        end => input[end..]
+            .find(['\r', '\n'])
+            .map_or(input.len(), |i| end + i),
+    };
+    input[..end]
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+/// Splits an input into blocks of lines separated by one or more blank lines, such as day 1's elves.
+///
+/// Each block is a slice of `input`, which can be split further with `lines`.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut blocks = Vec::new();
+    let mut block: Option<(&str, &str)> = None;
+    for line in lines(input) {
+        if !line.trim().is_empty() {
+            block = Some((block.map_or(line, |(first, _)| first), line));
+        } else if let Some((first, last)) = block.take() {
+            blocks.push(span(input, first, last));
+        }
+    }
+    match block {
+        Some((first, last)) => blocks.push(span(input, first, last)),
+        None if blocks.is_empty() => blocks.push(&input[..0]),
+        None => {}
+    }
+    blocks.into_iter()
+}
+
+/// Splits an input into the chars of a map, each with its position and the slice of `input` it was
+/// read from.
+pub fn char_grid(input: &str) -> impl Iterator<Item = (MapPos, char, &str)> {
+    lines(input).enumerate().flat_map(|(y, line)| {
+        line.char_indices().enumerate().map(move |(x, (i, c))| {
+            let pos = (x as MapPosComp, y as MapPosComp);
+            (pos, c, &line[i..i + c.len_utf8()])
+        })
+    })
+}
+
+/// The slice of `input` from the start of `first` to the end of `last`, which must both be slices of it.
+fn span<'a>(input: &'a str, first: &str, last: &str) -> &'a str {
+    let start = first.as_ptr() as usize - input.as_ptr() as usize;
+    let end = last.as_ptr() as usize + last.len() - input.as_ptr() as usize;
+    &input[start..end]
+}
+
+/// A problem with an input being read a little at a time: either it couldn't be read, or it didn't
+/// parse.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "could not read input: {}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Goes through a reader's lines one at a time, holding only the current line in memory, so that
+/// inputs far bigger than memory can still be solved.
+pub struct StreamLines<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> StreamLines<R> {
+    pub fn new(reader: R) -> StreamLines<R> {
+        StreamLines {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// Calls `f` with each line just as `lines` would split the whole input, returning how many
+    /// lines there were.
+    ///
+    /// Each line is parsed as an input of its own, so errors are moved to the line they were found
+    /// on. Blank lines are only passed on once a line with something on it follows them.
+    pub fn try_for_each(
+        mut self,
+        mut f: impl FnMut(&str) -> Result<(), ParseError>,
+    ) -> Result<usize, StreamError> {
+        let mut blank_lines = 0;
+        let mut passed_on = 0;
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+            self.line_number += 1;
+            let line = self.line.strip_suffix('\n').unwrap_or(&self.line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            for blank_line_number in self.line_number - blank_lines..self.line_number {
+                f("").map_err(|error| on_line(error, blank_line_number))?;
+            }
+            f(line).map_err(|error| on_line(error, self.line_number))?;
+            passed_on += blank_lines + 1;
+            blank_lines = 0;
+        }
+        if passed_on == 0 {
+            f("").map_err(|error| on_line(error, 1))?;
+            passed_on = 1;
+        }
+        return Ok(passed_on);
+    }
+}
+
+/// Moves an error found in a line parsed on its own to where that line is in the whole input.
+fn on_line(mut error: ParseError, line_number: usize) -> StreamError {
+    error.line += line_number - 1;
+    return StreamError::Parse(error);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crlf_and_trailing_blank_lines_are_ignored() {
+        let unix = lines("1\n2\n\n3").collect::<Vec<&str>>();
+        assert_eq!(
+            lines("1\r\n2\r\n\r\n3\r\n\r\n").collect::<Vec<&str>>(),
+            unix
+        );
+        assert_eq!(lines("1\n2\n\n3\n").collect::<Vec<&str>>(), unix);
+        assert_eq!(lines("").collect::<Vec<&str>>(), [""]);
+        assert_eq!(lines(" \n\n").collect::<Vec<&str>>(), [""]);
+    }
+
+    #[test]
+    fn source_args_name_one_input_around_other_flags() {
+        let args = |args: &[&str]| {
+            args.iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>()
+        };
+        let mut top = None;
+        let mut flag = |flag: &str, values: &mut std::slice::Iter<String>| {
+            match flag {
+                "--top" => top = Some(values.next()?.clone()),
+                _ => return None,
+            }
+            Some(())
+        };
+        assert_eq!(
+            parse_source_args(&args(&["--top", "5", "-"]), &mut flag),
+            Some(InputSource::Stdin)
+        );
+        assert_eq!(
+            parse_source_args(&args(&["--example"]), &mut flag),
+            Some(InputSource::Example)
+        );
+        assert_eq!(
+            parse_source_args(&args(&[]), &mut flag),
+            Some(InputSource::Bundled)
+        );
+        assert_eq!(
+            parse_source_args(&args(&["a", "--example"]), &mut flag),
+            None
+        );
+        assert_eq!(
+            parse_source_args(&args(&["--bottom", "5"]), &mut flag),
+            None
+        );
+        assert_eq!(parse_source_args(&args(&["--top"]), &mut flag), None);
+        assert_eq!(top.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn blocks_are_split_on_blank_lines() {
+        let input = "a\r\nb\r\n\r\n\r\nc\r\n";
+        let blocks = blocks(input).collect::<Vec<&str>>();
+        assert_eq!(blocks, ["a\r\nb", "c"]);
+        assert_eq!(lines(blocks[0]).collect::<Vec<&str>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn char_grid_cells_point_into_the_input() {
+        let input = "#.\r\n.#\r\n";
+        let walls = char_grid(input)
+            .filter(|&(_, c, _)| c == '#')
+            .map(|(pos, _, token)| (pos, token.as_ptr() as usize - input.as_ptr() as usize))
+            .collect::<Vec<(MapPos, usize)>>();
+        assert_eq!(walls, [((0, 0), 0), ((1, 1), 5)]);
+    }
+
+    #[test]
+    fn streamed_lines_match_lines_and_errors_point_at_their_line() {
+        for input in ["1\r\n2\r\n\r\n3\r\n\r\n", "", "\n\n", "x"] {
+            let mut streamed = Vec::new();
+            let count = StreamLines::new(input.as_bytes()).try_for_each(|line| {
+                streamed.push(line.to_owned());
+                Ok(())
+            });
+            assert_eq!(streamed, lines(input).collect::<Vec<&str>>());
+            assert_eq!(count.unwrap(), streamed.len());
+        }
+        let error = StreamLines::new("ok\n\nbad oops".as_bytes()).try_for_each(|line| match line {
+            "bad oops" => Err(ParseError::new(line, &line[4..], "expected no oops")),
+            _ => Ok(()),
+        });
+        let Err(StreamError::Parse(error)) = error else {
+            panic!("expected a parse error, got {:?}", error);
+        };
+        assert_eq!((error.line, error.column), (3, 5));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod answer;
+pub mod bench;
+pub mod direction;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod pos;
+pub mod search;
+pub mod solution;
+pub mod visualize;
@@ -0,0 +1,127 @@
+use std::{fmt, str::FromStr};
+
+/// A problem with a puzzle input, pinned to the token that caused it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line the token starts on.
+    pub line: usize,
+    /// The 1-based column the token starts at, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which must be a slice of `input` so that its position can be found.
+    pub fn new(input: &str, token: &str, message: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "token is not a slice of the input");
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for something expected just after `context`, which must be a slice of `input`.
+    pub fn missing(input: &str, context: &str, expected: &str) -> ParseError {
+        ParseError::new(
+            input,
+            &context[context.len()..],
+            format!("expected {}", expected),
+        )
+    }
+
+    /// Renders the error as a report quoting the offending line, with the token underlined.
+    pub fn report(&self, input: &str) -> String {
+        let line = input.split('\n').nth(self.line - 1).unwrap_or_default();
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let gutter = " ".repeat(self.line.to_string().len());
+        let token_len = self
+            .token
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count();
+        let underline = format!(
+            "{}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(token_len.max(1))
+        );
+        return format!(
+            "error: {}\n{} --> line {}, column {}\n{} |\n{} | {}\n{} | {}",
+            self.message,
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            line,
+            gutter,
+            underline
+        );
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a whole token as a number, reporting the token if it isn't one.
+pub fn parse_num<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::new(
+            input,
+            token,
+            format!("expected a number, found {:?}", token),
+        )
+    })
+}
+
+/// Splits `token` in two around `separator`, reporting the token if the separator is absent.
+pub fn split_pair<'a>(
+    input: &str,
+    token: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    token.split_once(separator).ok_or_else(|| {
+        ParseError::new(
+            input,
+            token,
+            format!("expected {:?} in {:?}", separator, token),
+        )
+    })
+}
+
+/// Strips the literal text `prefix` from the start of `token`, reporting the token if it doesn't
+/// start with it.
+pub fn strip_literal<'a>(input: &str, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    token
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(input, token, format!("expected {:?}", prefix)))
+}
+
+/// Parses a token made of two numbers joined by `separator`, such as a coordinate or a range.
+pub fn parse_num_pair<T: FromStr>(
+    input: &str,
+    token: &str,
+    separator: &str,
+) -> Result<(T, T), ParseError> {
+    let (first, second) = split_pair(input, token, separator)?;
+    Ok((
+        parse_num(input, first.trim())?,
+        parse_num(input, second.trim())?,
+    ))
+}
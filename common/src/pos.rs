@@ -0,0 +1,3 @@
+pub type MapPosComp = i64;
+
+pub type MapPos = (MapPosComp, MapPosComp);
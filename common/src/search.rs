@@ -0,0 +1,326 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+    time::Duration,
+};
+use tracing::info;
+// The standard clock panics on wasm32-unknown-unknown, where the solvers also run in the browser.
+use web_time::Instant;
+
+/// How often a long search reports how far it has got.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Counts what a search does, reporting its progress as it goes and a summary when it finishes.
+///
+/// Progress and summaries are `tracing` events inside whatever span the search runs in, so they
+/// cost next to nothing unless a subscriber is listening.
+pub struct SearchStats {
+    /// States whose successors have been generated.
+    pub expanded: u64,
+    /// Successors dropped because an equal or better copy had already been reached.
+    pub cache_hits: u64,
+    /// The most states ever waiting to be expanded at once.
+    pub max_frontier: usize,
+    /// States or moves skipped without being generated, by why they were skipped.
+    pub pruned: BTreeMap<&'static str, u64>,
+    last_report: Instant,
+}
+
+impl SearchStats {
+    pub fn new() -> SearchStats {
+        SearchStats {
+            expanded: 0,
+            cache_hits: 0,
+            max_frontier: 0,
+            pruned: BTreeMap::new(),
+            last_report: Instant::now(),
+        }
+    }
+
+    /// Records that a state is being expanded with `frontier` others still waiting.
+    pub fn expand(&mut self, frontier: usize) {
+        self.expanded += 1;
+        self.max_frontier = self.max_frontier.max(frontier);
+        // Checking the clock on every expansion would slow the tightest searches down noticeably.
+        if self.expanded.is_multiple_of(4096) && self.last_report.elapsed() >= PROGRESS_INTERVAL {
+            self.last_report = Instant::now();
+            info!(
+                expanded = self.expanded,
+                frontier,
+                cache_hits = self.cache_hits,
+                "searching"
+            );
+        }
+    }
+
+    pub fn cache_hit(&mut self) {
+        self.cache_hits += 1;
+    }
+
+    pub fn prune(&mut self, reason: &'static str) {
+        *self.pruned.entry(reason).or_default() += 1;
+    }
+
+    /// Reports the totals, along with how many states the search reached.
+    pub fn finish(&self, reached: usize) {
+        info!(
+            expanded = self.expanded,
+            reached,
+            cache_hits = self.cache_hits,
+            max_frontier = self.max_frontier,
+            pruned = %self.pruned_summary(),
+            "search finished"
+        );
+    }
+
+    /// How many were pruned for each reason, like `12 too far, 3 already open`.
+    pub fn pruned_summary(&self) -> String {
+        if self.pruned.is_empty() {
+            return "none".to_owned();
+        }
+        return self
+            .pruned
+            .iter()
+            .map(|(reason, count)| format!("{} {}", count, reason))
+            .collect::<Vec<String>>()
+            .join(", ");
+    }
+}
+
+impl Default for SearchStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Everything a search found: how far each reached state is from the nearest start, and how it got there.
+pub struct SearchResult<S, C> {
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    fn new() -> SearchResult<S, C> {
+        SearchResult {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The shortest known distance to every state the search reached, starts included.
+    ///
+    /// States still waiting to be expanded when a goal was found may not have their final distance.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The goal state the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The states along the way from one of the starts to `state`, both ends included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        return Some(path);
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Searches outwards from every start at once, where each step to a successor costs one, until a
+/// state satisfying `is_goal` is reached. Pass `|_| false` to reach everything instead.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let _span = tracing::info_span!("bfs").entered();
+    let mut stats = SearchStats::new();
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        stats.expand(queue.len());
+        let distance = result.distances[&state];
+        for next in successors(&state) {
+            if result.distances.contains_key(&next) {
+                stats.cache_hit();
+                continue;
+            }
+            result.distances.insert(next.clone(), distance + 1);
+            result.parents.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+    stats.finish(result.distances.len());
+    return result;
+}
+
+/// Like `bfs`, but with `successors` giving the cost of stepping to each successor.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but expanding first the states that `heuristic` estimates are closest to a goal.
+///
+/// The distance found to the goal is only the shortest if the heuristic never overestimates.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let _span = tracing::info_span!("astar").entered();
+    let mut stats = SearchStats::new();
+    let mut result = SearchResult::new();
+    // The queue refers to states by their index here, so that states don't need to be ordered.
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if result
+            .distances
+            .insert(start.clone(), C::default())
+            .is_none()
+        {
+            queue.push(Reverse((heuristic(&start), C::default(), states.len())));
+            states.push(start);
+        }
+    }
+    while let Some(Reverse((_, distance, index))) = queue.pop() {
+        let state = states[index].clone();
+        if result.distances[&state] < distance {
+            stats.prune("stale queue entries");
+            continue;
+        }
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        stats.expand(queue.len());
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if result
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                stats.cache_hit();
+                continue;
+            }
+            result.distances.insert(next.clone(), next_distance);
+            result.parents.insert(next.clone(), state.clone());
+            queue.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+    stats.finish(result.distances.len());
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph where the direct edge from 0 to 3 is longer than going around through 1 and 2.
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps_and_reconstructs_paths() {
+        let search = bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |&n| n == 3);
+        assert_eq!(search.goal_distance(), Some(1));
+        assert_eq!(search.goal_path(), Some(vec![0, 3]));
+    }
+
+    #[test]
+    fn bfs_searches_from_every_start() {
+        let search = bfs([0i32, 10], |&n| [n + 1, n - 1], |&n| n == 7);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![10, 9, 8, 7]));
+        let search = bfs([0], |&n| (n < 5).then_some(n + 1), |_| false);
+        assert_eq!(search.distances().len(), 6);
+        assert_eq!(search.goal(), None);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_find_the_cheapest_path() {
+        let search = dijkstra([0], edges, |&n| n == 3);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+        let search = astar([0], edges, |&n| 3 - n as u32, |&n| n == 3);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn stats_count_expansions_and_prunes_by_reason() {
+        let mut stats = SearchStats::new();
+        assert_eq!(stats.pruned_summary(), "none");
+        stats.expand(3);
+        stats.expand(1);
+        stats.prune("too far");
+        stats.prune("already open");
+        stats.prune("too far");
+        assert_eq!(stats.expanded, 2);
+        assert_eq!(stats.max_frontier, 3);
+        assert_eq!(stats.pruned_summary(), "1 already open, 2 too far");
+    }
+}
@@ -0,0 +1,295 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::input::{parse_source_args, read_input, InputSource};
+use crate::{answer::Answer, input::StreamError, parse::ParseError, visualize::Observer};
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{env, process, time::Instant};
+use std::{io::BufRead, time::Duration};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(num: u8) -> Option<Part> {
+        match num {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A day's puzzle: how to parse its input, and how to answer each part from the parsed input.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::None
+    }
+
+    /// Answers a part while `observer` watches each step of the simulation behind it.
+    ///
+    /// Days that don't simulate anything step by step just answer the part.
+    fn watch(input: &Self::Input<'_>, part: Part, _observer: &mut dyn Observer) -> Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+}
+
+/// A day that can also answer straight from a reader, going through the input once while holding
+/// only a bounded amount of it, so that inputs too big for memory can still be solved.
+pub trait StreamSolution {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError>;
+}
+
+/// A day whose answers depend on constants that the puzzle states in its text rather than in the
+/// input, such as how many minutes a search runs for, so that they can be changed without editing
+/// the day.
+///
+/// The day's `Solution` answers with the default config, which holds the real input's constants.
+pub trait ConfiguredSolution: Solution {
+    type Config: Default + Serialize + DeserializeOwned;
+
+    /// The constants that the puzzle's example uses, where they differ from the real input's.
+    fn example_config() -> Self::Config {
+        Self::Config::default()
+    }
+
+    /// Like `Solution::parse`, for days whose constants decide what a valid input is.
+    fn parse_with<'a>(
+        input: &'a str,
+        _config: &Self::Config,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part1_with(input: &Self::Input<'_>, config: &Self::Config) -> Answer;
+
+    fn part2_with(_input: &Self::Input<'_>, _config: &Self::Config) -> Answer {
+        Answer::None
+    }
+
+    /// Like `Solution::watch`, with the given constants.
+    fn watch_with(
+        input: &Self::Input<'_>,
+        part: Part,
+        config: &Self::Config,
+        _observer: &mut dyn Observer,
+    ) -> Answer {
+        match part {
+            Part::One => Self::part1_with(input, config),
+            Part::Two => Self::part2_with(input, config),
+        }
+    }
+}
+
+/// Parses the input and answers a single part, so that a day can be called without knowing its types.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer, ParseError> {
+    let parsed = S::parse(input)?;
+    let answer = match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    };
+    return Ok(answer);
+}
+
+/// Like `solve`, but with `observer` watching the simulation step by step.
+pub fn watch<S: Solution>(
+    input: &str,
+    part: Part,
+    observer: &mut dyn Observer,
+) -> Result<Answer, ParseError> {
+    let parsed = S::parse(input)?;
+    return Ok(S::watch(&parsed, part, observer));
+}
+
+/// Like `solve`, with the given constants in place of the real input's.
+pub fn solve_with<S: ConfiguredSolution>(
+    input: &str,
+    part: Part,
+    config: &S::Config,
+) -> Result<Answer, ParseError> {
+    let parsed = S::parse_with(input, config)?;
+    let answer = match part {
+        Part::One => S::part1_with(&parsed, config),
+        Part::Two => S::part2_with(&parsed, config),
+    };
+    return Ok(answer);
+}
+
+/// Like `watch`, with the given constants in place of the real input's.
+pub fn watch_with<S: ConfiguredSolution>(
+    input: &str,
+    part: Part,
+    config: &S::Config,
+    observer: &mut dyn Observer,
+) -> Result<Answer, ParseError> {
+    let parsed = S::parse_with(input, config)?;
+    return Ok(S::watch_with(&parsed, part, config, observer));
+}
+
+/// How answers are printed: as readable lines, or as one JSON record per answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// One answer as emitted by `--format json`.
+#[derive(Serialize)]
+pub struct AnswerRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a Answer,
+    /// Time taken to answer the part, including parsing the input.
+    pub elapsed_ms: f64,
+}
+
+/// Entry point for each day's own binary: answers both parts against the input named on the command line.
+///
+/// Accepts a path, `-` for stdin, or `--example` for the bundled example, and defaults to the bundled input.
+/// `--format json` prints JSON records instead of text. Left out of the WebAssembly build, which
+/// has no command line or files to read.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_day<S: Solution>(day: u8, day_dir: &str) {
+    run_day_with::<S, ()>(
+        day,
+        day_dir,
+        |_| (),
+        parse_unconfigured::<S>,
+        |parsed, part, _| match part {
+            Part::One => S::part1(parsed),
+            Part::Two => S::part2(parsed),
+        },
+    );
+}
+
+/// Like `run_day`, but solves the example with the example's constants.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_configured_day<S: ConfiguredSolution>(day: u8, day_dir: &str) {
+    let config = |source: &InputSource| match source {
+        InputSource::Example => S::example_config(),
+        _ => S::Config::default(),
+    };
+    run_day_with::<S, S::Config>(
+        day,
+        day_dir,
+        config,
+        S::parse_with,
+        |parsed, part, config| match part {
+            Part::One => S::part1_with(parsed, config),
+            Part::Two => S::part2_with(parsed, config),
+        },
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_unconfigured<'a, S: Solution>(input: &'a str, _: &()) -> Result<S::Input<'a>, ParseError> {
+    S::parse(input)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_day_with<S: Solution, C>(
+    day: u8,
+    day_dir: &str,
+    config: impl Fn(&InputSource) -> C,
+    parse: for<'a> fn(&'a str, &C) -> Result<S::Input<'a>, ParseError>,
+    answer: impl Fn(&S::Input<'_>, Part, &C) -> Answer,
+) {
+    let args = env::args().collect::<Vec<String>>();
+    let (source, format) = parse_day_args(&args[1..]).unwrap_or_else(|| {
+        eprintln!(
+            "usage: {} [--format text|json] [PATH | - | --example]",
+            args[0]
+        );
+        process::exit(2);
+    });
+    let contents = match read_input(day_dir, &source) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+    let config = config(&source);
+    let start = Instant::now();
+    let parsed = match parse(&contents, &config) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.report(&contents));
+            process::exit(1);
+        }
+    };
+    let parse_time = start.elapsed();
+    for part in Part::ALL {
+        let start = Instant::now();
+        let answer = answer(&parsed, part, &config);
+        print_answer(format, day, part, &answer, parse_time + start.elapsed());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_day_args(args: &[String]) -> Option<(InputSource, OutputFormat)> {
+    let mut format = OutputFormat::Text;
+    let source = parse_source_args(args, |flag, values| {
+        match flag {
+            "--format" => format = OutputFormat::from_name(values.next()?)?,
+            _ => return None,
+        }
+        Some(())
+    })?;
+    return Some((source, format));
+}
+
+/// Prints a part's answer in the given format.
+///
+/// As text, multi-line answers such as day 10's screen start on their own line.
+pub fn print_answer(format: OutputFormat, day: u8, part: Part, answer: &Answer, elapsed: Duration) {
+    match format {
+        OutputFormat::Text => {
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                println!("Part {}:\n{}", part.number(), answer);
+            } else {
+                println!("Part {}: {}", part.number(), answer);
+            }
+        }
+        OutputFormat::Json => {
+            let record = AnswerRecord {
+                day,
+                part: part.number(),
+                answer,
+                elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            };
+            println!("{}", serde_json::to_string(&record).unwrap());
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    grid::Grid,
+    pos::{MapPos, MapPosComp},
+};
+use std::{
+    fmt,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// A picture of a simulation's state: a rectangle of tiles, placed on the map at its top left corner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    origin: MapPos,
+    width: usize,
+    height: usize,
+    tiles: Vec<char>,
+}
+
+impl Frame {
+    /// Creates a frame covering the inclusive bounds, with every tile set to `fill`.
+    pub fn new(bounds: (MapPos, MapPos), fill: char) -> Frame {
+        let (min, max) = bounds;
+        let width = (max.0 - min.0 + 1).max(0) as usize;
+        let height = (max.1 - min.1 + 1).max(0) as usize;
+        Frame {
+            origin: min,
+            width,
+            height,
+            tiles: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a frame just big enough to hold every point, filling the space between them with `fill`.
+    ///
+    /// Later points are drawn over earlier ones at the same position.
+    pub fn from_points(points: impl IntoIterator<Item = (MapPos, char)>, fill: char) -> Frame {
+        let points = points.into_iter().collect::<Vec<(MapPos, char)>>();
+        let mut min = (MapPosComp::MAX, MapPosComp::MAX);
+        let mut max = (MapPosComp::MIN, MapPosComp::MIN);
+        for &((x, y), _) in &points {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+        if points.is_empty() {
+            (min, max) = ((0, 0), (-1, -1));
+        }
+        let mut frame = Frame::new((min, max), fill);
+        for (pos, tile) in points {
+            frame.set(pos, tile);
+        }
+        return frame;
+    }
+
+    /// Creates a frame covering a grid's bounds, choosing a tile for each position.
+    pub fn from_grid<T>(grid: &Grid<T>, mut tile: impl FnMut(Option<&T>) -> char) -> Frame {
+        let mut frame = Frame::new(grid.bounds().unwrap_or(((0, 0), (-1, -1))), ' ');
+        let (min, max) = frame.bounds();
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                frame.set((x, y), tile(grid.get((x, y))));
+            }
+        }
+        return frame;
+    }
+
+    /// The inclusive top left and bottom right corners of the frame.
+    pub fn bounds(&self) -> (MapPos, MapPos) {
+        (
+            self.origin,
+            (
+                self.origin.0 + self.width as MapPosComp - 1,
+                self.origin.1 + self.height as MapPosComp - 1,
+            ),
+        )
+    }
+
+    fn index(&self, pos: MapPos) -> Option<usize> {
+        let x = pos.0 - self.origin.0;
+        let y = pos.1 - self.origin.1;
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        return Some(y as usize * self.width + x as usize);
+    }
+
+    pub fn get(&self, pos: MapPos) -> Option<char> {
+        self.index(pos).map(|i| self.tiles[i])
+    }
+
+    /// Sets the tile at a position, doing nothing if the position is outside the frame.
+    pub fn set(&mut self, pos: MapPos, tile: char) {
+        if let Some(i) = self.index(pos) {
+            self.tiles[i] = tile;
+        }
+    }
+
+    /// Every tile the frame uses.
+    pub fn tiles(&self) -> impl Iterator<Item = char> + '_ {
+        self.tiles.iter().copied()
+    }
+
+    /// Turns the frame into pixels, row by row, covering the inclusive `canvas` bounds so that frames of
+    /// different sizes line up. Each tile becomes a `scale` by `scale` square, and the parts of the canvas
+    /// outside the frame get the pixel for `None`.
+    pub fn pixels<P: Clone>(
+        &self,
+        canvas: (MapPos, MapPos),
+        scale: usize,
+        mut pixel: impl FnMut(Option<char>) -> P,
+    ) -> Vec<P> {
+        let (min, max) = canvas;
+        let mut pixels = Vec::new();
+        for y in min.1..=max.1 {
+            let row = (min.0..=max.0)
+                .flat_map(|x| vec![pixel(self.get((x, y))); scale])
+                .collect::<Vec<P>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+        return pixels;
+    }
+
+    /// Encodes the frame as a binary PPM image, coloured with `palette`.
+    pub fn to_ppm(&self, scale: usize, palette: impl Fn(char) -> [u8; 3]) -> Vec<u8> {
+        let mut ppm =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        let pixels = self.pixels(self.bounds(), scale, |tile| palette(tile.unwrap_or(' ')));
+        ppm.extend(pixels.into_iter().flatten());
+        return ppm;
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.tiles.chunks(self.width.max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        return Ok(());
+    }
+}
+
+/// The colour for a tile when drawing frames as images: dark for empty space, light for walls and rock,
+/// and a fixed colour picked from the char itself for everything else.
+pub fn palette(tile: char) -> [u8; 3] {
+    match tile {
+        ' ' => [0, 0, 0],
+        '.' => [24, 24, 36],
+        '#' | '|' | '-' | '+' => [190, 190, 200],
+        _ => {
+            let hash = (tile as u32).wrapping_mul(2_654_435_761);
+            [
+                (hash >> 24) as u8 | 0x60,
+                (hash >> 16) as u8 | 0x60,
+                (hash >> 8) as u8 | 0x60,
+            ]
+        }
+    }
+}
+
+/// Watches a simulation, which reports to it after every step.
+pub trait Observer {
+    /// Called after each step with a way to draw the simulation as it now stands. Drawing can be slow,
+    /// so observers should only call `draw` for the steps they want to see.
+    fn step(&mut self, draw: &dyn Fn() -> Frame);
+}
+
+/// Watches nothing, for running a simulation at full speed.
+impl Observer for () {
+    fn step(&mut self, _draw: &dyn Fn() -> Frame) {}
+}
+
+/// Passes the frame of every `every`th step to a callback, along with the number of that step.
+pub struct Sampler<F> {
+    every: usize,
+    steps: usize,
+    on_frame: F,
+}
+
+impl<F: FnMut(usize, Frame)> Sampler<F> {
+    pub fn new(every: usize, on_frame: F) -> Sampler<F> {
+        Sampler {
+            every: every.max(1),
+            steps: 0,
+            on_frame,
+        }
+    }
+
+    /// How many steps the simulation has taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<F: FnMut(usize, Frame)> Observer for Sampler<F> {
+    fn step(&mut self, draw: &dyn Fn() -> Frame) {
+        self.steps += 1;
+        if self.steps.is_multiple_of(self.every) {
+            (self.on_frame)(self.steps, draw());
+        }
+    }
+}
+
+/// Plays a simulation in the terminal, redrawing every `every`th step in place and pausing for `delay`.
+pub fn animate(every: usize, delay: Duration) -> Sampler<impl FnMut(usize, Frame)> {
+    Sampler::new(every, move |step, frame| {
+        let mut stdout = io::stdout().lock();
+        // Moves the cursor home and clears the screen before drawing over it.
+        let _ = write!(stdout, "\x1b[H\x1b[2J{}\nStep {}\n", frame, step);
+        let _ = stdout.flush();
+        thread::sleep(delay);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_fit_their_points() {
+        let frame = Frame::from_points([((-1, 0), '#'), ((1, 1), 'o'), ((1, 1), '@')], '.');
+        assert_eq!(frame.bounds(), ((-1, 0), (1, 1)));
+        assert_eq!(frame.to_string(), "#..\n..@");
+        assert_eq!(Frame::from_points([], '.').to_string(), "");
+    }
+
+    #[test]
+    fn pixels_line_frames_up_on_a_shared_canvas() {
+        let frame = Frame::from_points([((1, 0), '#')], '.');
+        let pixels = frame.pixels(((0, 0), (1, 0)), 2, |tile| tile.unwrap_or(' '));
+        assert_eq!(pixels.into_iter().collect::<String>(), "  ##  ##");
+        let ppm = frame.to_ppm(1, palette);
+        assert!(ppm.starts_with(b"P6\n1 1\n255\n"));
+        assert_eq!(ppm.len(), 11 + 3);
+    }
+
+    #[test]
+    fn samplers_only_draw_the_steps_they_keep() {
+        let mut kept = Vec::new();
+        let mut sampler =
+            Sampler::new(2, |step, frame: Frame| kept.push((step, frame.to_string())));
+        for i in 0..5 {
+            sampler.step(&|| Frame::from_points([((0, 0), char::from(b'0' + i))], '.'));
+        }
+        assert_eq!(sampler.steps(), 5);
+        assert_eq!(kept, [(2, "1".to_owned()), (4, "3".to_owned())]);
+    }
+}
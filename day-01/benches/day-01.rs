@@ -0,0 +1 @@
+common::bench_day!("day-01", advent_2022_day_01::Day01);
@@ -0,0 +1,65 @@
+//! Times streaming day 1 through an inventory that's made up as it's read, so that inventories
+//! far bigger than memory can be timed. `DAY01_STREAM_MIB` sets the inventory's size, which is
+//! 64 MiB unless it's set.
+
+use advent_2022_day_01::{inventory::aggregate, Day01};
+use common::generate::generate;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::{
+    env,
+    hint::black_box,
+    io::{self, BufReader, Read},
+};
+
+/// How many elves the generated piece of the inventory that's repeated has.
+const ELVES_PER_CHUNK: usize = 10_000;
+
+/// One generated chunk of elves, repeated until the inventory is `remaining` bytes long, so that
+/// reading it costs next to nothing next to aggregating it.
+struct RepeatedInventory<'a> {
+    chunk: &'a [u8],
+    read: usize,
+    remaining: usize,
+}
+
+impl Read for RepeatedInventory<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.read == self.chunk.len() {
+            self.read = 0;
+        }
+        let count = buf
+            .len()
+            .min(self.chunk.len() - self.read)
+            .min(self.remaining);
+        buf[..count].copy_from_slice(&self.chunk[self.read..self.read + count]);
+        self.read += count;
+        self.remaining -= count;
+        return Ok(count);
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let mib = env::var("DAY01_STREAM_MIB")
+        .ok()
+        .and_then(|mib| mib.parse::<usize>().ok())
+        .unwrap_or(64);
+    let size = mib * 1024 * 1024;
+    let chunk = format!("{}\n\n", generate::<Day01>(0, ELVES_PER_CHUNK)).into_bytes();
+    let mut group = c.benchmark_group("day-01-stream");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(size as u64));
+    group.bench_function(format!("top3-{}MiB", mib), |b| {
+        b.iter(|| {
+            let reader = BufReader::new(RepeatedInventory {
+                chunk: &chunk,
+                read: 0,
+                remaining: size,
+            });
+            aggregate(reader, black_box(3)).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+use advent_2022_day_01::{
+    inventory::{elf_stats, histogram, top_elves, HistogramChart},
+    Day01,
+};
+use common::{
+    input::{parse_source_args, read_input, InputSource},
+    solution::Solution,
+};
+use std::{env, process};
+
+/// How long the fullest bucket's bar is.
+const CHART_WIDTH: usize = 50;
+
+struct Options {
+    source: InputSource,
+    top: usize,
+    buckets: usize,
+}
+
+/// Prints what each elf carries, which elves carry the most, and how the totals are spread.
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let options = parse_args(&args[1..]).unwrap_or_else(|| {
+        eprintln!(
+            "usage: {} [--top K] [--buckets N] [PATH | - | --example]",
+            args[0]
+        );
+        process::exit(2);
+    });
+    let contents = read_input(env!("CARGO_MANIFEST_DIR"), &options.source).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let elves = Day01::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("{}", err.report(&contents));
+        process::exit(1);
+    });
+
+    let stats = elf_stats(&elves);
+    println!(
+        "{:>5}  {:>5}  {:>8}  {:>9}  {:>9}",
+        "Elf", "Items", "Total", "Mean", "Median"
+    );
+    for elf in &stats {
+        println!(
+            "{:>5}  {:>5}  {:>8}  {:>9.1}  {:>9.1}",
+            elf.number, elf.items, elf.total, elf.mean, elf.median
+        );
+    }
+
+    let totals = stats.iter().map(|elf| elf.total).collect::<Vec<u64>>();
+    let ranking = top_elves(&totals, options.top);
+    println!();
+    println!(
+        "Top {} carry {}: {}",
+        ranking.top.len(),
+        ranking.total(),
+        ranking
+            .top
+            .iter()
+            .map(|elf| format!("elf {} ({})", elf.number, elf.total))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+    if let (Some(last), false) = (ranking.top.last(), ranking.tied.is_empty()) {
+        println!(
+            "Tied with elf {} at {}: {}",
+            last.number,
+            last.total,
+            ranking
+                .tied
+                .iter()
+                .map(|elf| format!("elf {}", elf.number))
+                .chain(
+                    (ranking.tied_count > ranking.tied.len())
+                        .then(|| format!("{} more", ranking.tied_count - ranking.tied.len()))
+                )
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+
+    println!();
+    let buckets = histogram(&totals, options.buckets);
+    print!(
+        "{}",
+        HistogramChart {
+            buckets: &buckets,
+            width: CHART_WIDTH,
+        }
+    );
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let (mut top, mut buckets) = (3, 10);
+    let source = parse_source_args(args, |flag, values| {
+        match flag {
+            "--top" => top = values.next()?.parse().ok()?,
+            "--buckets" => buckets = values.next()?.parse().ok().filter(|&n| n > 0)?,
+            _ => return None,
+        }
+        Some(())
+    })?;
+    return Some(Options {
+        source,
+        top,
+        buckets,
+    });
+}
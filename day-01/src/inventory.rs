@@ -0,0 +1,440 @@
+use common::{
+    input::{StreamError, StreamLines},
+    parse::{parse_num, ParseError},
+};
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, io::BufRead};
+
+/// An elf, numbered from 1 in the order the input lists them, and the calories it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub number: usize,
+    pub total: u64,
+}
+
+impl ElfTotal {
+    /// Orders elves by who carries more, with the earlier elf first among equal totals.
+    fn rank(&self) -> (u64, Reverse<usize>) {
+        (self.total, Reverse(self.number))
+    }
+}
+
+/// The elves carrying the most calories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ranking {
+    /// As many elves as were asked for, or every elf if there are fewer, from the most calories
+    /// down. Of elves carrying the same amount, the earlier ones make it in.
+    pub top: Vec<ElfTotal>,
+    /// The earliest `MAX_TIED` of the elves left out of `top` only because they carry as much as
+    /// its last elf.
+    pub tied: Vec<ElfTotal>,
+    /// How many elves were left out of `top` that way, listed in `tied` or not.
+    pub tied_count: usize,
+}
+
+/// The most tied elves a ranking lists, so that it takes the same room however many elves tie.
+pub const MAX_TIED: usize = 10;
+
+impl Ranking {
+    /// How many calories the top elves carry between them, which is the same whichever of any
+    /// tied elves make it in. It's widened so that adding up elves with the largest totals a `u64`
+    /// holds can't overflow.
+    pub fn total(&self) -> u128 {
+        self.top.iter().map(|elf| elf.total as u128).sum()
+    }
+}
+
+/// Keeps track of the `k` elves carrying the most calories as elves are added, holding only those
+/// elves and up to `MAX_TIED` of the elves tied with the last of them.
+pub struct TopElves {
+    k: usize,
+    /// The top elves so far, with the one that would be dropped first on top.
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    tied: Vec<ElfTotal>,
+    tied_count: usize,
+}
+
+impl TopElves {
+    pub fn new(k: usize) -> TopElves {
+        TopElves {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+            tied: Vec::with_capacity(MAX_TIED),
+            tied_count: 0,
+        }
+    }
+
+    pub fn push(&mut self, elf: ElfTotal) {
+        if self.k == 0 {
+            return;
+        }
+        self.heap.push(Reverse(elf.rank()));
+        if self.heap.len() <= self.k {
+            return;
+        }
+        let Reverse((total, Reverse(number))) = self.heap.pop().unwrap();
+        let cutoff = self.cutoff().unwrap();
+        // The cutoff only ever rises, so elves below it can never tie again.
+        if self.tied.first().is_some_and(|tied| tied.total != cutoff) {
+            self.tied.clear();
+            self.tied_count = 0;
+        }
+        if total == cutoff {
+            self.tie(ElfTotal { number, total });
+        }
+    }
+
+    /// Counts an elf tied at the cutoff, listing it if it's among the earliest `MAX_TIED`.
+    fn tie(&mut self, elf: ElfTotal) {
+        self.tied_count += 1;
+        if self.tied.len() < MAX_TIED {
+            self.tied.push(elf);
+            return;
+        }
+        let latest = (0..self.tied.len())
+            .max_by_key(|&i| self.tied[i].number)
+            .unwrap();
+        if elf.number < self.tied[latest].number {
+            self.tied[latest] = elf;
+        }
+    }
+
+    /// The smallest total still in the top elves, once there are any.
+    fn cutoff(&self) -> Option<u64> {
+        self.heap.peek().map(|Reverse((total, _))| *total)
+    }
+
+    pub fn finish(self) -> Ranking {
+        let mut top = self
+            .heap
+            .into_iter()
+            .map(|Reverse((total, Reverse(number)))| ElfTotal { number, total })
+            .collect::<Vec<ElfTotal>>();
+        top.sort_by_key(|elf| Reverse(elf.rank()));
+        let mut tied = self.tied;
+        tied.sort_by_key(|elf| elf.number);
+        return Ranking {
+            top,
+            tied,
+            tied_count: self.tied_count,
+        };
+    }
+}
+
+/// Finds the `k` elves carrying the most calories, given each elf's total in order.
+pub fn top_elves(totals: &[u64], k: usize) -> Ranking {
+    let mut top = TopElves::new(k);
+    for (i, &total) in totals.iter().enumerate() {
+        top.push(ElfTotal {
+            number: i + 1,
+            total,
+        });
+    }
+    return top.finish();
+}
+
+/// Finds the `k` elves carrying the most calories while reading the inventory a line at a time,
+/// holding only the current elf's running total and the top elves so far, so that an inventory of
+/// any size can be read.
+///
+/// Elves are split and numbered the way `Day01::parse` splits them, so a run of blank lines ends
+/// only one elf, and an inventory with no items at all is an error.
+pub fn aggregate(reader: impl BufRead, k: usize) -> Result<Ranking, StreamError> {
+    let mut top = TopElves::new(k);
+    let mut current: Option<ElfTotal> = None;
+    let mut elves = 0;
+    StreamLines::new(reader).try_for_each(|line| {
+        if line.is_empty() {
+            if let Some(elf) = current.take() {
+                top.push(elf);
+            }
+        } else {
+            let calories = parse_num(line, line)?;
+            let elf = current.get_or_insert_with(|| {
+                elves += 1;
+                ElfTotal {
+                    number: elves,
+                    total: 0,
+                }
+            });
+            elf.total = add_calories(line, line, elf.total, calories)?;
+        }
+        Ok(())
+    })?;
+    if let Some(elf) = current {
+        top.push(elf);
+    }
+    if elves == 0 {
+        return Err(StreamError::Parse(no_elves()));
+    }
+    return Ok(top.finish());
+}
+
+/// The error for an inventory with no items at all, pointing at its start.
+pub fn no_elves() -> ParseError {
+    ParseError::missing("", "", "at least one elf")
+}
+
+/// Adds an item to an elf's running total, reporting the item if the total no longer fits.
+pub fn add_calories(input: &str, item: &str, total: u64, calories: u64) -> Result<u64, ParseError> {
+    total.checked_add(calories).ok_or_else(|| {
+        ParseError::new(
+            input,
+            item,
+            format!("the elf's calories add up to more than {}", u64::MAX),
+        )
+    })
+}
+
+/// What one elf is carrying.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElfStats {
+    pub number: usize,
+    pub items: usize,
+    pub total: u64,
+    /// The average calories per item, which is 0 for an elf with no items.
+    pub mean: f64,
+    /// The middle item's calories, or the average of the middle two, which is 0 for an elf with
+    /// no items.
+    pub median: f64,
+}
+
+/// Takes the stats of elves whose totals fit in a `u64`, as parsing checks.
+pub fn elf_stats(elves: &[Vec<u64>]) -> Vec<ElfStats> {
+    elves
+        .iter()
+        .enumerate()
+        .map(|(i, items)| {
+            let total = items.iter().sum::<u64>();
+            let mut sorted = items.clone();
+            sorted.sort_unstable();
+            let middle = sorted.len() / 2;
+            let (mean, median) = match sorted.len() {
+                0 => (0.0, 0.0),
+                len if len.is_multiple_of(2) => (
+                    total as f64 / len as f64,
+                    (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0,
+                ),
+                len => (total as f64 / len as f64, sorted[middle] as f64),
+            };
+            ElfStats {
+                number: i + 1,
+                items: items.len(),
+                total,
+                mean,
+                median,
+            }
+        })
+        .collect()
+}
+
+/// How many elves carry between `low` and `high` calories, inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub low: u64,
+    pub high: u64,
+    pub count: usize,
+}
+
+/// Splits the range from the smallest total to the largest into at most `buckets` equal buckets and
+/// counts the elves in each.
+pub fn histogram(totals: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return vec![];
+    };
+    // Widened, since the span of totals from 0 to u64::MAX doesn't fit in a u64.
+    let span = (max - min) as u128 + 1;
+    let width = span.div_ceil(buckets.max(1) as u128);
+    let mut histogram = (0..span.div_ceil(width))
+        .map(|i| {
+            let low = min as u128 + i * width;
+            Bucket {
+                low: low as u64,
+                high: (low + width - 1).min(max as u128) as u64,
+                count: 0,
+            }
+        })
+        .collect::<Vec<Bucket>>();
+    for &total in totals {
+        histogram[((total - min) as u128 / width) as usize].count += 1;
+    }
+    return histogram;
+}
+
+/// Draws each bucket as a row with a bar of `#`, where the fullest bucket's bar is `width` long.
+pub struct HistogramChart<'a> {
+    pub buckets: &'a [Bucket],
+    pub width: usize,
+}
+
+impl fmt::Display for HistogramChart<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let most = self.buckets.iter().map(|bucket| bucket.count).max();
+        let digits = self
+            .buckets
+            .last()
+            .map_or(1, |bucket| bucket.high.to_string().len());
+        for bucket in self.buckets {
+            let bar = bucket.count * self.width / most.unwrap_or(1).max(1);
+            write!(f, "{:>digits$}-{:>digits$} |", bucket.low, bucket.high)?;
+            if bar > 0 {
+                write!(f, " {}", "#".repeat(bar))?;
+            }
+            writeln!(f, " {}", bucket.count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(totals: &[(usize, u64)]) -> Vec<ElfTotal> {
+        totals
+            .iter()
+            .map(|&(number, total)| ElfTotal { number, total })
+            .collect()
+    }
+
+    #[test]
+    fn top_elves_match_sorting_for_every_k() {
+        // Scattered totals with plenty of ties.
+        let totals = (0..200).map(|i| i * 7919 % 50).collect::<Vec<u64>>();
+        let mut sorted = totals.iter().copied().enumerate().collect::<Vec<_>>();
+        sorted.sort_by_key(|&(i, total)| (Reverse(total), i));
+        for k in 0..=totals.len() + 1 {
+            let ranking = top_elves(&totals, k);
+            let expected = sorted
+                .iter()
+                .take(k)
+                .map(|&(i, total)| ElfTotal {
+                    number: i + 1,
+                    total,
+                })
+                .collect::<Vec<ElfTotal>>();
+            assert_eq!(ranking.top, expected, "k = {}", k);
+            let cutoff = expected.last().map(|elf| elf.total);
+            let expected_tied = sorted
+                .iter()
+                .skip(k)
+                .filter(|&&(_, total)| Some(total) == cutoff)
+                .count();
+            assert_eq!(ranking.tied_count, expected_tied, "k = {}", k);
+            assert_eq!(ranking.tied.len(), expected_tied.min(MAX_TIED), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn ties_at_the_cutoff_are_reported_and_the_earlier_elf_wins() {
+        let ranking = top_elves(&[5, 9, 7, 9, 7, 3, 7], 2);
+        assert_eq!(ranking.top, elves(&[(2, 9), (4, 9)]));
+        assert_eq!(ranking.tied, vec![]);
+        assert_eq!(ranking.total(), 18);
+
+        let ranking = top_elves(&[5, 9, 7, 9, 7, 3, 7], 3);
+        assert_eq!(ranking.top, elves(&[(2, 9), (4, 9), (3, 7)]));
+        assert_eq!(ranking.tied, elves(&[(5, 7), (7, 7)]));
+
+        let ranking = top_elves(&[4, 4, 4], 1);
+        assert_eq!(ranking.top, elves(&[(1, 4)]));
+        assert_eq!(ranking.tied, elves(&[(2, 4), (3, 4)]));
+        assert_eq!(ranking.tied_count, 2);
+    }
+
+    #[test]
+    fn only_the_earliest_tied_elves_are_listed() {
+        let totals = [vec![1; 50], vec![4; 1000], vec![9]].concat();
+        let ranking = top_elves(&totals, 2);
+        assert_eq!(ranking.top, elves(&[(1051, 9), (51, 4)]));
+        assert_eq!(ranking.tied_count, 999);
+        let expected = (52..52 + MAX_TIED)
+            .map(|number| (number, 4))
+            .collect::<Vec<(usize, u64)>>();
+        assert_eq!(ranking.tied, elves(&expected));
+        // Once the cutoff rises past them, none of them are tied any more.
+        let ranking = top_elves(&[totals, vec![9]].concat(), 2);
+        assert_eq!((ranking.tied.len(), ranking.tied_count), (0, 0));
+    }
+
+    #[test]
+    fn aggregating_a_stream_keeps_the_same_top_elves_as_ranking_them_all() {
+        let inventory = "1000\n2000\n\n4000\n\n3000\n\n\n500\n2500";
+        for k in 0..6 {
+            assert_eq!(
+                aggregate(inventory.as_bytes(), k).unwrap(),
+                top_elves(&[3000, 4000, 3000, 3000], k),
+                "k = {}",
+                k
+            );
+        }
+    }
+
+    #[test]
+    fn aggregating_reports_the_item_that_overflows_its_elf() {
+        let inventory = format!("5\n\n{}\n\n{}\n1", u64::MAX, u64::MAX);
+        let Err(StreamError::Parse(err)) = aggregate(inventory.as_bytes(), 3) else {
+            panic!("expected the total to overflow");
+        };
+        assert_eq!((err.line, err.token.as_str()), (6, "1"));
+    }
+
+    #[test]
+    fn stats_give_the_mean_and_median_of_each_elfs_items() {
+        let stats = elf_stats(&[vec![3000, 1000, 2000], vec![4000], vec![5000, 6000], vec![]]);
+        let summary = stats
+            .iter()
+            .map(|elf| (elf.number, elf.items, elf.total, elf.mean, elf.median))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (1, 3, 6000, 2000.0, 2000.0),
+                (2, 1, 4000, 4000.0, 4000.0),
+                (3, 2, 11000, 5500.0, 5500.0),
+                (4, 0, 0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn histogram_buckets_cover_every_total_once() {
+        let totals = [6000, 4000, 11000, 24000, 10000];
+        let buckets = histogram(&totals, 4);
+        assert_eq!(
+            buckets,
+            [
+                Bucket {
+                    low: 4000,
+                    high: 9000,
+                    count: 2
+                },
+                Bucket {
+                    low: 9001,
+                    high: 14001,
+                    count: 2
+                },
+                Bucket {
+                    low: 14002,
+                    high: 19002,
+                    count: 0
+                },
+                Bucket {
+                    low: 19003,
+                    high: 24000,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(histogram(&[7, 7], 10).len(), 1);
+        assert_eq!(histogram(&[], 10), vec![]);
+
+        let chart = HistogramChart {
+            buckets: &buckets,
+            width: 4,
+        };
+        assert_eq!(
+            chart.to_string(),
+            " 4000- 9000 | #### 2\n 9001-14001 | #### 2\n14002-19002 | 0\n19003-24000 | ## 1\n"
+        );
+    }
+}
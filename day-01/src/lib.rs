@@ -0,0 +1,199 @@
+pub mod inventory;
+
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::{blocks, lines, StreamError},
+    parse::{parse_num, ParseError},
+    solution::{Part, Solution, StreamSolution},
+};
+use inventory::{add_calories, aggregate, no_elves, top_elves};
+use std::io::BufRead;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Vec<u64>>;
+
+    /// Also checks that there's at least one elf, and that no elf's calories add up to more than a
+    /// `u64` holds, so that their totals can be taken without overflowing.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        if input.trim().is_empty() {
+            return Err(no_elves());
+        }
+        blocks(input)
+            .map(|cal_list_str| {
+                let mut total = 0;
+                lines(cal_list_str)
+                    .map(|cal| {
+                        let calories = parse_num(input, cal)?;
+                        total = add_calories(input, cal, total, calories)?;
+                        Ok(calories)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Answer {
+        let ranking = top_elves(&get_elf_totals(elves), top_count(Part::One));
+        ranking.total().into()
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> Answer {
+        let ranking = top_elves(&get_elf_totals(elves), top_count(Part::Two));
+        ranking.total().into()
+    }
+}
+
+/// How many of the elves carrying the most calories each part adds up.
+fn top_count(part: Part) -> usize {
+    match part {
+        Part::One => 1,
+        Part::Two => 3,
+    }
+}
+
+impl StreamSolution for Day01 {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let ranking = aggregate(reader, top_count(part))?;
+        Ok(ranking.total().into())
+    }
+}
+
+fn get_elf_totals(elves: &[Vec<u64>]) -> Vec<u64> {
+    elves.iter().map(|elf| elf.iter().sum()).collect()
+}
+
+impl Generator for Day01 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let elves = (0..size.max(1)).map(|_| {
+            let items = rng.gen_range(1..=6);
+            join_lines((0..items).map(|_| rng.gen_range(1000..=60000).to_string()))
+        });
+        elves.collect::<Vec<String>>().join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::ElfTotal;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day01>(EXAMPLE, Part::One), Ok(Answer::Int(24000)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day01>(EXAMPLE, Part::Two), Ok(Answer::Int(45000)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day01>(INPUT, Part::One), Ok(Answer::Int(68775)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day01>(INPUT, Part::Two), Ok(Answer::Int(202585)));
+    }
+
+    #[test]
+    fn the_elf_carrying_the_most_is_the_one_reported() {
+        let elves = Day01::parse(EXAMPLE).unwrap();
+        let ranking = top_elves(&get_elf_totals(&elves), 1);
+        assert_eq!(
+            ranking.top,
+            [ElfTotal {
+                number: 4,
+                total: 24000
+            }]
+        );
+    }
+
+    #[test]
+    fn totals_past_u32_are_added_up_and_past_u64_are_errors() {
+        let big = "4000000000\n4000000000\n\n1\n\n4000000000";
+        assert_eq!(solve::<Day01>(big, Part::One), Ok(Answer::Int(8000000000)));
+        assert_eq!(solve::<Day01>(big, Part::Two), Ok(Answer::Int(12000000001)));
+
+        let overflowing = format!("1\n\n{}\n1", u64::MAX);
+        let err = Day01::parse(&overflowing).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (4, "1"));
+
+        let max = u64::MAX.to_string();
+        let huge = [max.as_str(), max.as_str()].join("\n\n");
+        assert_eq!(
+            solve::<Day01>(&huge, Part::Two),
+            Ok(Answer::Text((u64::MAX as u128 * 2).to_string()))
+        );
+    }
+
+    /// Finds the best elves by repeatedly taking the largest total that hasn't been taken yet.
+    fn brute_force_top_totals(input: &str, count: usize) -> u32 {
+        let mut totals = input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|item| item.parse::<u32>().unwrap()).sum())
+            .collect::<Vec<u32>>();
+        let mut sum = 0;
+        for _ in 0..count.min(totals.len()) {
+            let (best, total) = totals
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, total)| **total)
+                .unwrap();
+            sum += total;
+            totals.remove(best);
+        }
+        sum
+    }
+
+    #[test]
+    fn aggregating_splits_elves_the_way_parsing_does() {
+        let inventories = [
+            "1\n\n\n\n2",
+            "\n\n5\n6\n\n \n7\n\n\n",
+            "1000\n2000\n\n4000\n\n3000\n\n\n500\n2500",
+        ];
+        for inventory in inventories {
+            let totals = get_elf_totals(&Day01::parse(inventory).unwrap());
+            for k in 0..totals.len() + 2 {
+                assert_eq!(
+                    aggregate(inventory.as_bytes(), k).unwrap(),
+                    top_elves(&totals, k),
+                    "{:?}, k = {}",
+                    inventory,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn aggregating_an_empty_inventory_is_an_error_like_parsing_it() {
+        for inventory in ["", "\n\n"] {
+            let Err(StreamError::Parse(streamed)) = aggregate(inventory.as_bytes(), 3) else {
+                panic!("expected {:?} to be an error", inventory);
+            };
+            assert_eq!(streamed.message, "expected at least one elf");
+            assert_eq!(Day01::parse(inventory), Err(streamed));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day01>(seed, size);
+            prop_assert_eq!(solve::<Day01>(&input, Part::One), Ok(Answer::from(brute_force_top_totals(&input, 1))));
+            prop_assert_eq!(solve::<Day01>(&input, Part::Two), Ok(Answer::from(brute_force_top_totals(&input, 3))));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-02", advent_2022_day_02::Day02);
@@ -0,0 +1,289 @@
+use crate::{
+    game::{Game, Outcome},
+    Round,
+};
+
+/// How many rounds, or moves, end each way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutcomeCounts {
+    pub won: usize,
+    pub drawn: usize,
+    pub lost: usize,
+}
+
+impl OutcomeCounts {
+    fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => self.won += 1,
+            Outcome::Draw => self.drawn += 1,
+            Outcome::Lose => self.lost += 1,
+        }
+    }
+}
+
+/// One way of reading the second column's letters as moves, and what following the guide that way
+/// scores.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    /// The move played for each response letter, in the order the game lists the letters.
+    pub moves: Vec<usize>,
+    pub total: u64,
+    pub outcomes: OutcomeCounts,
+}
+
+/// How one round goes under the best and worst mappings, and how often each outcome comes up if
+/// the move is picked at random.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundBreakdown {
+    pub opponent: usize,
+    /// The index of the response letter in the second column.
+    pub letter: usize,
+    pub best: Outcome,
+    pub worst: Outcome,
+    /// How many of the game's moves end the round each way.
+    pub random: OutcomeCounts,
+}
+
+/// What the strategy guide could have meant by its second column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analysis {
+    /// The mapping scoring the most, and of mappings scoring the same, the first in lexicographic
+    /// order of moves, so that the guide's own reading wins ties.
+    pub best: Mapping,
+    /// The mapping scoring the least, with ties broken the same way.
+    pub worst: Mapping,
+    /// The mean total if every round's move were picked uniformly at random.
+    pub expected: f64,
+    pub rounds: Vec<RoundBreakdown>,
+}
+
+/// The most moves a game can have to be analyzed, since there are 9! = 362880 mappings to try for
+/// 9 moves and ten times as many for 10.
+pub const MAX_MOVES: usize = 9;
+
+/// Tries every mapping of response letters to moves, of which there are n! for a game of n moves,
+/// reading the second column only as moves. There's no analysis if the game has more than
+/// `MAX_MOVES` moves, if some round's second column isn't a response letter, or if some mapping's
+/// total is too big for a `u64`.
+pub fn analyze(rounds: &[Round], game: &Game) -> Result<Analysis, String> {
+    let moves = game.move_count();
+    if moves > MAX_MOVES {
+        return Err(format!(
+            "a game of {} moves has too many mappings to try, so only games of up to {} moves can \
+             be analyzed",
+            moves, MAX_MOVES
+        ));
+    }
+    let letters = rounds
+        .iter()
+        .map(|round| Some((round.opponent, round.response?)))
+        .collect::<Option<Vec<(usize, usize)>>>()
+        .ok_or_else(|| "some rounds give an outcome rather than a move".to_owned())?;
+    // How many rounds there are of each opponent move and response letter, so that each mapping is
+    // scored without going back over the rounds.
+    let mut tally = vec![vec![0_u64; moves]; moves];
+    for &(opponent, letter) in &letters {
+        tally[opponent][letter] += 1;
+    }
+
+    let mut mapping = (0..moves).collect::<Vec<usize>>();
+    let mut best = (0, mapping.clone());
+    let mut worst = (u64::MAX, mapping.clone());
+    loop {
+        let mut total = Some(0_u64);
+        for (opponent, counts) in tally.iter().enumerate() {
+            for (letter, &count) in counts.iter().enumerate() {
+                let own_move = mapping[letter];
+                let score = game.score(own_move, game.play(own_move, opponent));
+                total = total.and_then(|total| total.checked_add(count.checked_mul(score)?));
+            }
+        }
+        let total = total.ok_or_else(|| {
+            format!(
+                "some reading of the second column scores more than {}",
+                u64::MAX
+            )
+        })?;
+        if total > best.0 {
+            best = (total, mapping.clone());
+        }
+        if total < worst.0 {
+            worst = (total, mapping.clone());
+        }
+        if !next_permutation(&mut mapping) {
+            break;
+        }
+    }
+
+    let mut expected = 0.0;
+    let breakdowns = letters
+        .iter()
+        .map(|&(opponent, letter)| {
+            let mut random = OutcomeCounts::default();
+            let mut points = 0;
+            for own_move in 0..moves {
+                let outcome = game.play(own_move, opponent);
+                random.add(outcome);
+                points += game.score(own_move, outcome);
+            }
+            expected += points as f64 / moves as f64;
+            RoundBreakdown {
+                opponent,
+                letter,
+                best: game.play(best.1[letter], opponent),
+                worst: game.play(worst.1[letter], opponent),
+                random,
+            }
+        })
+        .collect::<Vec<RoundBreakdown>>();
+
+    let summarize = |(total, moves): (u64, Vec<usize>), pick: fn(&RoundBreakdown) -> Outcome| {
+        let mut outcomes = OutcomeCounts::default();
+        for round in &breakdowns {
+            outcomes.add(pick(round));
+        }
+        Mapping {
+            moves,
+            total,
+            outcomes,
+        }
+    };
+    return Ok(Analysis {
+        best: summarize(best, |round| round.best),
+        worst: summarize(worst, |round| round.worst),
+        expected,
+        rounds: breakdowns,
+    });
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order, or returns false if they're
+/// already in the last.
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        return false;
+    };
+    let successor = items.iter().rposition(|&item| item > items[pivot]).unwrap();
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+    return true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{GameRules, MoveRule},
+        Day02,
+    };
+    use common::{
+        answer::Answer,
+        solution::{solve_with, ConfiguredSolution, Part},
+    };
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+
+    #[test]
+    fn permutations_come_in_lexicographic_order() {
+        let mut items = vec![0, 1, 2];
+        let mut seen = vec![items.clone()];
+        while next_permutation(&mut items) {
+            seen.push(items.clone());
+        }
+        assert_eq!(
+            seen,
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn example_can_be_won_or_lost_outright() {
+        let game = Game::default();
+        let rounds = Day02::parse_with(EXAMPLE, &game).unwrap();
+        let analysis = analyze(&rounds, &game).unwrap();
+        // X is scissors, Y paper and Z rock.
+        assert_eq!(analysis.best.moves, vec![2, 1, 0]);
+        assert_eq!(analysis.best.total, 24);
+        assert_eq!(analysis.best.outcomes.won, 3);
+        // X is rock, Y scissors and Z paper.
+        assert_eq!(analysis.worst.moves, vec![0, 2, 1]);
+        assert_eq!(analysis.worst.total, 6);
+        assert_eq!(analysis.worst.outcomes.lost, 3);
+        // Whatever the opponent plays, a random move scores 5 on average and ends each way once
+        // in three.
+        assert_eq!(analysis.expected, 15.0);
+        let random = OutcomeCounts {
+            won: 1,
+            drawn: 1,
+            lost: 1,
+        };
+        assert!(analysis.rounds.iter().all(|round| round.random == random));
+    }
+
+    #[test]
+    fn best_and_worst_match_relettering_the_game() {
+        let input = "A X\nA Y\nB Y\nC X\nC Z\nB Y";
+        let game = Game::default();
+        let analysis = analyze(&Day02::parse_with(input, &game).unwrap(), &game).unwrap();
+        let mut totals = vec![];
+        let mut mapping = vec![0, 1, 2];
+        loop {
+            let mut rules = GameRules::from(game.clone());
+            for (letter, &own_move) in ["X", "Y", "Z"].iter().zip(&mapping) {
+                rules.moves[own_move].response = letter.to_string();
+            }
+            let relettered = Game::try_from(rules).unwrap();
+            match solve_with::<Day02>(input, Part::One, &relettered) {
+                Ok(Answer::Int(total)) => totals.push(total),
+                other => panic!("unexpected answer {:?}", other),
+            }
+            if !next_permutation(&mut mapping) {
+                break;
+            }
+        }
+        assert_eq!(totals.iter().max(), Some(&(analysis.best.total as i64)));
+        assert_eq!(totals.iter().min(), Some(&(analysis.worst.total as i64)));
+    }
+
+    #[test]
+    fn totals_add_up_points_past_a_u32() {
+        let mut rules = GameRules::from(Game::default());
+        rules.win.points = u32::MAX;
+        let game = Game::try_from(rules).unwrap();
+        let rounds = Day02::parse_with(EXAMPLE, &game).unwrap();
+        let analysis = analyze(&rounds, &game).unwrap();
+        assert_eq!(analysis.best.total, 3 * (u32::MAX as u64) + 6);
+        assert_eq!(analysis.worst.total, 6);
+    }
+
+    #[test]
+    fn games_with_too_many_moves_are_refused() {
+        let mut rules = GameRules::from(Game::default());
+        let moves = MAX_MOVES + 1;
+        // Each move beats every later one, except that the last beats the first.
+        rules.moves = (0..moves)
+            .map(|i| MoveRule {
+                name: format!("m{}", i),
+                opponent: format!("O{}", i),
+                response: format!("R{}", i),
+                points: 1,
+                beats: (i + 1..moves)
+                    .filter(|&j| (i, j) != (0, moves - 1))
+                    .chain((i == moves - 1).then_some(0))
+                    .map(|j| format!("m{}", j))
+                    .collect(),
+            })
+            .collect();
+        let game = Game::try_from(rules).unwrap();
+        let rounds = Day02::parse_with("O0 R1", &game).unwrap();
+        assert!(analyze(&rounds, &game)
+            .unwrap_err()
+            .contains("too many mappings"));
+    }
+}
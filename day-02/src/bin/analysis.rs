@@ -0,0 +1,135 @@
+use advent_2022_day_02::{
+    analysis::{analyze, Mapping, OutcomeCounts, MAX_MOVES},
+    game::{Game, Outcome},
+    Day02,
+};
+use common::{
+    input::{parse_source_args, read_input, InputSource},
+    solution::ConfiguredSolution,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+struct Options {
+    source: InputSource,
+    rules: Option<PathBuf>,
+}
+
+/// Prints which reading of the strategy guide's second column as moves scores the most and the
+/// least, what playing at random would score, and how each round goes.
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let options = parse_args(&args[1..]).unwrap_or_else(|| {
+        eprintln!("usage: {} [--rules PATH] [PATH | - | --example]", args[0]);
+        eprintln!("games of up to {} moves can be analyzed", MAX_MOVES);
+        process::exit(2);
+    });
+    let game = match &options.rules {
+        Some(path) => read_rules(path).unwrap_or_else(|err| {
+            eprintln!("error: {}: {}", path.display(), err);
+            process::exit(1);
+        }),
+        None => Game::default(),
+    };
+    let contents = read_input(env!("CARGO_MANIFEST_DIR"), &options.source).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let rounds = Day02::parse_with(&contents, &game).unwrap_or_else(|err| {
+        eprintln!("{}", err.report(&contents));
+        process::exit(1);
+    });
+    let analysis = analyze(&rounds, &game).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+
+    print_mapping("Best", &analysis.best, &game);
+    print_mapping("Worst", &analysis.worst, &game);
+    println!(
+        "Random: {:.1} expected ({:.1} a round)",
+        analysis.expected,
+        analysis.expected / analysis.rounds.len().max(1) as f64
+    );
+
+    println!();
+    println!(
+        "{:>5}  {:>8}  {:>6}  {:>5}  {:>5}  {:>17}",
+        "Round", "Opponent", "Letter", "Best", "Worst", "Random (W/D/L)"
+    );
+    for (i, round) in analysis.rounds.iter().enumerate() {
+        println!(
+            "{:>5}  {:>8}  {:>6}  {:>5}  {:>5}  {:>17}",
+            i + 1,
+            game.opponent_letter(round.opponent),
+            game.response_letter(round.letter),
+            outcome_name(round.best),
+            outcome_name(round.worst),
+            counts(round.random)
+        );
+    }
+}
+
+fn print_mapping(label: &str, mapping: &Mapping, game: &Game) {
+    let letters = mapping
+        .moves
+        .iter()
+        .enumerate()
+        .map(|(letter, &own_move)| {
+            format!(
+                "{} = {}",
+                game.response_letter(letter),
+                game.move_name(own_move)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!(
+        "{}: {} scores {} (W/D/L {})",
+        label,
+        letters,
+        mapping.total,
+        counts(mapping.outcomes)
+    );
+}
+
+fn outcome_name(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::Win => "won",
+        Outcome::Draw => "drawn",
+        Outcome::Lose => "lost",
+    }
+}
+
+fn counts(outcomes: OutcomeCounts) -> String {
+    format!("{}/{}/{}", outcomes.won, outcomes.drawn, outcomes.lost)
+}
+
+/// Reads the game from the `[day-02]` table of a rules file like `resources/rpsls.toml`.
+fn read_rules(path: &Path) -> Result<Game, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let mut file = contents
+        .parse::<toml::Table>()
+        .map_err(|err| err.to_string())?;
+    let rules = file
+        .remove("day-02")
+        .ok_or_else(|| "no [day-02] table".to_owned())?;
+    return rules
+        .try_into()
+        .map_err(|err: toml::de::Error| err.to_string());
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let mut rules = None;
+    let source = parse_source_args(args, |flag, values| {
+        match flag {
+            "--rules" => rules = Some(PathBuf::from(values.next()?)),
+            _ => return None,
+        }
+        Some(())
+    })?;
+    return Some(Options { source, rules });
+}
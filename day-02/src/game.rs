@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// How a round ends for the player being scored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A game like rock paper scissors as a rules file gives it, before it's been checked.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GameRules {
+    /// Every move, in the order tried when more than one would end a round the way it has to.
+    pub moves: Vec<MoveRule>,
+    pub lose: OutcomeRule,
+    pub draw: OutcomeRule,
+    pub win: OutcomeRule,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MoveRule {
+    pub name: String,
+    /// The letter in the first column of the strategy guide when the opponent plays this move.
+    pub opponent: String,
+    /// The letter in the second column when it's read as this move.
+    pub response: String,
+    /// What playing this move scores, whatever the outcome.
+    pub points: u32,
+    /// The names of the moves this move beats.
+    pub beats: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeRule {
+    /// The letter in the second column when it's read as this outcome.
+    pub letter: String,
+    pub points: u32,
+}
+
+/// A game whose rules have been checked: of any two different moves, exactly one beats the other,
+/// and every move beats something and is beaten by something, so that any round can be lost,
+/// drawn or won.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "GameRules", into = "GameRules")]
+pub struct Game {
+    rules: GameRules,
+    /// Whether the move at the first index beats the move at the second.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    pub fn move_count(&self) -> usize {
+        self.rules.moves.len()
+    }
+
+    pub fn move_name(&self, index: usize) -> &str {
+        &self.rules.moves[index].name
+    }
+
+    pub fn opponent_letter(&self, index: usize) -> &str {
+        &self.rules.moves[index].opponent
+    }
+
+    pub fn response_letter(&self, index: usize) -> &str {
+        &self.rules.moves[index].response
+    }
+
+    pub fn opponent_move(&self, letter: &str) -> Option<usize> {
+        self.rules
+            .moves
+            .iter()
+            .position(|rule| rule.opponent == letter)
+    }
+
+    pub fn response_move(&self, letter: &str) -> Option<usize> {
+        self.rules
+            .moves
+            .iter()
+            .position(|rule| rule.response == letter)
+    }
+
+    pub fn outcome(&self, letter: &str) -> Option<Outcome> {
+        [Outcome::Lose, Outcome::Draw, Outcome::Win]
+            .into_iter()
+            .find(|&outcome| self.outcome_rule(outcome).letter == letter)
+    }
+
+    /// The letters that can stand for the opponent's move.
+    pub fn opponent_letters(&self) -> Vec<&str> {
+        self.rules
+            .moves
+            .iter()
+            .map(|rule| rule.opponent.as_str())
+            .collect()
+    }
+
+    /// The letters that can stand for a response or an outcome, responses first.
+    pub fn second_column_letters(&self) -> Vec<&str> {
+        let mut letters = self
+            .rules
+            .moves
+            .iter()
+            .map(|rule| rule.response.as_str())
+            .collect::<Vec<&str>>();
+        for outcome in [&self.rules.lose, &self.rules.draw, &self.rules.win] {
+            if !letters.contains(&outcome.letter.as_str()) {
+                letters.push(&outcome.letter);
+            }
+        }
+        return letters;
+    }
+
+    /// How a round ends for the player making `own_move` against `other_move`.
+    pub fn play(&self, own_move: usize, other_move: usize) -> Outcome {
+        if own_move == other_move {
+            Outcome::Draw
+        } else if self.beats[own_move][other_move] {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// The first move, in the rules' order, that ends a round against `other_move` with `outcome`,
+    /// which the rules' checks guarantee there is.
+    pub fn move_for(&self, other_move: usize, outcome: Outcome) -> usize {
+        (0..self.beats.len())
+            .find(|&own_move| self.play(own_move, other_move) == outcome)
+            .unwrap()
+    }
+
+    /// What a round scores, which is a `u64` so that no two rules' points can overflow it.
+    pub fn score(&self, own_move: usize, outcome: Outcome) -> u64 {
+        u64::from(self.rules.moves[own_move].points) + u64::from(self.outcome_rule(outcome).points)
+    }
+
+    fn outcome_rule(&self, outcome: Outcome) -> &OutcomeRule {
+        match outcome {
+            Outcome::Lose => &self.rules.lose,
+            Outcome::Draw => &self.rules.draw,
+            Outcome::Win => &self.rules.win,
+        }
+    }
+}
+
+/// Rock paper scissors as the puzzle plays it.
+impl Default for Game {
+    fn default() -> Self {
+        let rule = |name: &str, opponent: &str, response: &str, points, beats: &str| MoveRule {
+            name: name.to_owned(),
+            opponent: opponent.to_owned(),
+            response: response.to_owned(),
+            points,
+            beats: vec![beats.to_owned()],
+        };
+        let outcome = |letter: &str, points| OutcomeRule {
+            letter: letter.to_owned(),
+            points,
+        };
+        Game::try_from(GameRules {
+            moves: vec![
+                rule("rock", "A", "X", 1, "scissors"),
+                rule("paper", "B", "Y", 2, "rock"),
+                rule("scissors", "C", "Z", 3, "paper"),
+            ],
+            lose: outcome("X", 0),
+            draw: outcome("Y", 3),
+            win: outcome("Z", 6),
+        })
+        .unwrap()
+    }
+}
+
+impl TryFrom<GameRules> for Game {
+    type Error = String;
+
+    fn try_from(rules: GameRules) -> Result<Self, Self::Error> {
+        let moves = &rules.moves;
+        // With fewer, some move can't both beat something and be beaten by something.
+        if moves.len() < 3 {
+            return Err(format!(
+                "a game needs at least 3 moves, but this one has {}",
+                moves.len()
+            ));
+        }
+        check_unique("move name", moves.iter().map(|rule| &rule.name))?;
+        check_unique("opponent letter", moves.iter().map(|rule| &rule.opponent))?;
+        check_unique("response letter", moves.iter().map(|rule| &rule.response))?;
+        check_unique(
+            "outcome letter",
+            [&rules.lose, &rules.draw, &rules.win].map(|outcome| &outcome.letter),
+        )?;
+
+        let mut beats = vec![vec![false; moves.len()]; moves.len()];
+        for (winner, rule) in moves.iter().enumerate() {
+            for name in &rule.beats {
+                let loser = moves
+                    .iter()
+                    .position(|other| &other.name == name)
+                    .ok_or_else(|| format!("{} beats {}, which isn't a move", rule.name, name))?;
+                if loser == winner {
+                    return Err(format!("{} can't beat itself", rule.name));
+                }
+                beats[winner][loser] = true;
+            }
+        }
+        for (a, first) in moves.iter().enumerate() {
+            for (b, second) in moves.iter().enumerate().skip(a + 1) {
+                match (beats[a][b], beats[b][a]) {
+                    (true, true) => {
+                        return Err(format!(
+                            "{} and {} both beat each other",
+                            first.name, second.name
+                        ))
+                    }
+                    (false, false) => {
+                        return Err(format!(
+                            "neither {} nor {} beats the other",
+                            first.name, second.name
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+            if !beats[a].contains(&true) {
+                return Err(format!("{} doesn't beat anything", first.name));
+            }
+            if !beats.iter().any(|row| row[a]) {
+                return Err(format!("nothing beats {}", first.name));
+            }
+        }
+        return Ok(Game { rules, beats });
+    }
+}
+
+impl From<Game> for GameRules {
+    fn from(game: Game) -> Self {
+        game.rules
+    }
+}
+
+fn check_unique<'a>(what: &str, items: impl IntoIterator<Item = &'a String>) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for item in items {
+        if !seen.insert(item) {
+            return Err(format!("the {} {:?} is used twice", what, item));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(beats: &[(&str, &[&str])]) -> GameRules {
+        let mut rules = GameRules::from(Game::default());
+        rules.moves = beats
+            .iter()
+            .enumerate()
+            .map(|(i, (name, beats))| MoveRule {
+                name: name.to_string(),
+                opponent: format!("O{}", i),
+                response: format!("R{}", i),
+                points: i as u32 + 1,
+                beats: beats.iter().map(|name| name.to_string()).collect(),
+            })
+            .collect();
+        rules
+    }
+
+    #[test]
+    fn every_outcome_can_be_reached_against_every_move() {
+        let game = Game::default();
+        for other_move in 0..3 {
+            for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                let own_move = game.move_for(other_move, outcome);
+                assert_eq!(game.play(own_move, other_move), outcome);
+            }
+        }
+        assert_eq!(game.play(0, 2), Outcome::Win);
+        assert_eq!(game.play(2, 0), Outcome::Lose);
+        assert_eq!(game.score(1, Outcome::Win), 8);
+    }
+
+    #[test]
+    fn scores_add_up_points_past_a_u32() {
+        let mut rules = GameRules::from(Game::default());
+        rules.moves[1].points = u32::MAX;
+        rules.win.points = u32::MAX;
+        let game = Game::try_from(rules).unwrap();
+        assert_eq!(game.score(1, Outcome::Win), 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn rules_that_leave_a_round_undecided_are_rejected() {
+        let cycle = rules(&[("a", &["c"]), ("b", &["a"]), ("c", &["b"])]);
+        assert!(Game::try_from(cycle).is_ok());
+
+        let undecided = rules(&[("a", &["c"]), ("b", &[]), ("c", &["b"])]);
+        assert_eq!(
+            Game::try_from(undecided),
+            Err("neither a nor b beats the other".to_owned())
+        );
+        let both = rules(&[("a", &["b", "c"]), ("b", &["a"]), ("c", &["b"])]);
+        assert_eq!(
+            Game::try_from(both),
+            Err("a and b both beat each other".to_owned())
+        );
+        let unbeaten = rules(&[("a", &["b", "c"]), ("b", &["c"]), ("c", &[])]);
+        assert_eq!(Game::try_from(unbeaten), Err("nothing beats a".to_owned()));
+        assert_eq!(
+            Game::try_from(rules(&[])),
+            Err("a game needs at least 3 moves, but this one has 0".to_owned())
+        );
+        assert_eq!(
+            Game::try_from(rules(&[("a", &["b"]), ("b", &[])])),
+            Err("a game needs at least 3 moves, but this one has 2".to_owned())
+        );
+        let unknown = rules(&[("a", &["d"]), ("b", &["a"]), ("c", &["b"])]);
+        assert_eq!(
+            Game::try_from(unknown),
+            Err("a beats d, which isn't a move".to_owned())
+        );
+        let mut repeated = rules(&[("a", &["c"]), ("b", &["a"]), ("c", &["b"])]);
+        repeated.moves[1].opponent = "O0".to_owned();
+        assert_eq!(
+            Game::try_from(repeated),
+            Err("the opponent letter \"O0\" is used twice".to_owned())
+        );
+    }
+}
@@ -0,0 +1,264 @@
+pub mod analysis;
+pub mod game;
+
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, SliceRandom, StdRng},
+    input::{lines, StreamError, StreamLines},
+    parse::{split_pair, ParseError},
+    solution::{ConfiguredSolution, Part, Solution, StreamSolution},
+};
+use game::{Game, Outcome};
+use std::io::BufRead;
+
+/// A line of the strategy guide, with the second column read both ways it can be, so that each
+/// part can use the reading it needs.
+pub struct Round {
+    opponent: usize,
+    /// The move the second column stands for, if it stands for one.
+    response: Option<usize>,
+    /// The outcome the second column stands for, if it stands for one.
+    outcome: Option<Outcome>,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Game::default())
+    }
+
+    fn part1(rounds: &Self::Input<'_>) -> Answer {
+        Self::part1_with(rounds, &Game::default())
+    }
+
+    fn part2(rounds: &Self::Input<'_>) -> Answer {
+        Self::part2_with(rounds, &Game::default())
+    }
+}
+
+/// The game is rock paper scissors unless a rules file says otherwise. A part has no answer if some
+/// round's second column doesn't stand for what that part reads it as, which can only happen when
+/// the game's response and outcome letters differ.
+impl ConfiguredSolution for Day02 {
+    type Config = Game;
+
+    fn parse_with<'a>(input: &'a str, game: &Game) -> Result<Self::Input<'a>, ParseError> {
+        lines(input)
+            .map(|line| parse_round(input, line, game))
+            .collect()
+    }
+
+    fn part1_with(rounds: &Self::Input<'_>, game: &Game) -> Answer {
+        total_score(rounds, |round| score_round_as_moves(round, game))
+    }
+
+    fn part2_with(rounds: &Self::Input<'_>, game: &Game) -> Answer {
+        total_score(rounds, |round| score_round_as_outcome(round, game))
+    }
+}
+
+impl StreamSolution for Day02 {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let game = Game::default();
+        let mut total = 0_u64;
+        StreamLines::new(reader).try_for_each(|line| {
+            let round = parse_round(line, line, &game)?;
+            let score = match part {
+                Part::One => score_round_as_moves(&round, &game),
+                Part::Two => score_round_as_outcome(&round, &game),
+            }
+            .unwrap();
+            total = add_score(line, line, total, score)?;
+            Ok(())
+        })?;
+        Ok(total.into())
+    }
+}
+
+fn parse_round(input: &str, line: &str, game: &Game) -> Result<Round, ParseError> {
+    let (p1, p2) = split_pair(input, line, " ")?;
+    let opponent = game.opponent_move(p1).ok_or_else(|| {
+        ParseError::new(
+            input,
+            p1,
+            format!("expected {}", one_of(&game.opponent_letters())),
+        )
+    })?;
+    let (response, outcome) = (game.response_move(p2), game.outcome(p2));
+    if response.is_none() && outcome.is_none() {
+        let expected = one_of(&game.second_column_letters());
+        return Err(ParseError::new(input, p2, format!("expected {}", expected)));
+    }
+    Ok(Round {
+        opponent,
+        response,
+        outcome,
+    })
+}
+
+/// Lists letters the way an error message would: "A, B or C".
+fn one_of(letters: &[&str]) -> String {
+    match letters.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+/// Adds up the rounds' scores as a `u128`, which no number of rounds held in memory can overflow.
+fn total_score(rounds: &[Round], score_round: impl Fn(&Round) -> Option<u64>) -> Answer {
+    match rounds
+        .iter()
+        .map(|round| score_round(round).map(u128::from))
+        .sum::<Option<u128>>()
+    {
+        Some(total) => total.into(),
+        None => Answer::None,
+    }
+}
+
+/// Adds a round's score to the total so far, reporting the round if the total no longer fits.
+fn add_score(input: &str, round: &str, total: u64, score: u64) -> Result<u64, ParseError> {
+    total.checked_add(score).ok_or_else(|| {
+        ParseError::new(
+            input,
+            round,
+            format!("the scores add up to more than {}", u64::MAX),
+        )
+    })
+}
+
+/// Scores a round where the second column is the move to make.
+fn score_round_as_moves(round: &Round, game: &Game) -> Option<u64> {
+    let own_move = round.response?;
+    Some(game.score(own_move, game.play(own_move, round.opponent)))
+}
+
+/// Scores a round where the second column is how the round has to end.
+fn score_round_as_outcome(round: &Round, game: &Game) -> Option<u64> {
+    let outcome = round.outcome?;
+    Some(game.score(game.move_for(round.opponent, outcome), outcome))
+}
+
+impl Generator for Day02 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        join_lines((0..size.max(1)).map(|_| {
+            let opponent = ["A", "B", "C"].choose(rng).unwrap();
+            let response = ["X", "Y", "Z"].choose(rng).unwrap();
+            format!("{} {}", opponent, response)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, solve_with, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+    const RPSLS: &str = include_str!("../resources/rpsls.toml");
+    const RPSLS_EXAMPLE: &str = include_str!("../resources/example-rpsls.txt");
+
+    fn rpsls() -> Game {
+        let mut file = RPSLS.parse::<toml::Table>().unwrap();
+        file.remove("day-02").unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day02>(EXAMPLE, Part::One), Ok(Answer::Int(15)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day02>(EXAMPLE, Part::Two), Ok(Answer::Int(12)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day02>(INPUT, Part::One), Ok(Answer::Int(12156)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day02>(INPUT, Part::Two), Ok(Answer::Int(10835)));
+    }
+
+    #[test]
+    fn rules_files_can_add_moves() {
+        let game = rpsls();
+        assert_eq!(
+            solve_with::<Day02>(RPSLS_EXAMPLE, Part::One, &game),
+            Ok(Answer::Int(34))
+        );
+        assert_eq!(
+            solve_with::<Day02>(RPSLS_EXAMPLE, Part::Two, &game),
+            Ok(Answer::Int(25))
+        );
+    }
+
+    #[test]
+    fn rounds_that_name_no_outcome_leave_part_2_unanswered() {
+        let game = rpsls();
+        assert_eq!(
+            solve_with::<Day02>("A V\nB W", Part::One, &game),
+            Ok(Answer::Int(9))
+        );
+        assert_eq!(
+            solve_with::<Day02>("A V\nB W", Part::Two, &game),
+            Ok(Answer::None)
+        );
+        let err = solve_with::<Day02>("F X", Part::One, &game).unwrap_err();
+        assert_eq!(err.message, "expected A, B, C, D or E");
+        let err = solve::<Day02>("A V", Part::One).unwrap_err();
+        assert_eq!(err.message, "expected X, Y or Z");
+    }
+
+    /// Scores each round directly, trying every response in part 2 until one gives the outcome.
+    fn brute_force_scores(input: &str) -> (u32, u32) {
+        let (mut part1, mut part2) = (0, 0);
+        let outcome = |opponent: u32, response: u32| match (response + 3 - opponent) % 3 {
+            0 => 3,
+            1 => 6,
+            _ => 0,
+        };
+        for line in input.lines() {
+            let bytes = line.as_bytes();
+            let opponent = (bytes[0] - b'A') as u32;
+            let column = (bytes[2] - b'X') as u32;
+            part1 += column + 1 + outcome(opponent, column);
+            let response = (0..3)
+                .find(|&response| outcome(opponent, response) == column * 3)
+                .unwrap();
+            part2 += response + 1 + column * 3;
+        }
+        (part1, part2)
+    }
+
+    #[test]
+    fn stream_totals_past_u32_and_report_overflow() {
+        assert_eq!(
+            add_score("A X", "A X", u32::MAX as u64, 4),
+            Ok(u32::MAX as u64 + 4)
+        );
+        let err = add_score("A X", "A X", u64::MAX - 3, 4).unwrap_err();
+        assert_eq!(err.token, "A X");
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day02>(seed, size);
+            let (part1, part2) = brute_force_scores(&input);
+            prop_assert_eq!(solve::<Day02>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day02>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-03", day_03::Day03);
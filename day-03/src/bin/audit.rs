@@ -0,0 +1,53 @@
+use common::{
+    input::{parse_source_args, read_input, InputSource},
+    solution::Solution,
+};
+use day_03::{rucksack::audit, Day03};
+use std::{env, num::NonZeroUsize, process};
+
+struct Options {
+    source: InputSource,
+    group_size: NonZeroUsize,
+}
+
+/// Lists every rucksack whose compartments don't share exactly one item, and every group whose
+/// rucksacks don't share exactly one badge, exiting with 1 if there are any.
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let options = parse_args(&args[1..]).unwrap_or_else(|| {
+        eprintln!("usage: {} [--group-size N] [PATH | - | --example]", args[0]);
+        process::exit(2);
+    });
+    let contents = read_input(env!("CARGO_MANIFEST_DIR"), &options.source).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let rucksacks = Day03::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("{}", err.report(&contents));
+        process::exit(1);
+    });
+    let anomalies = audit(&rucksacks, options.group_size);
+    for anomaly in &anomalies {
+        println!("{}", anomaly);
+    }
+    if !anomalies.is_empty() {
+        process::exit(1);
+    }
+    println!(
+        "All {} rucksacks and {} groups share exactly one item",
+        rucksacks.len(),
+        rucksacks.len() / options.group_size.get()
+    );
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let mut group_size = NonZeroUsize::new(3).unwrap();
+    let source = parse_source_args(args, |flag, values| {
+        match flag {
+            "--group-size" => group_size = values.next()?.parse().ok()?,
+            _ => return None,
+        }
+        Some(())
+    })?;
+    return Some(Options { source, group_size });
+}
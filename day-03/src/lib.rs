@@ -0,0 +1,272 @@
+pub mod rucksack;
+
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::{lines, StreamError, StreamLines},
+    parse::ParseError,
+    solution::{ConfiguredSolution, Part, Solution, StreamSolution},
+};
+use rucksack::{badge, badges, misplaced_items, priority, ItemSet, Rucksack};
+use serde::{Deserialize, Serialize};
+use std::{io::BufRead, mem, num::NonZeroUsize};
+
+pub struct Day03;
+
+/// How many elves carry each badge between them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day03Config {
+    pub group_size: NonZeroUsize,
+}
+
+impl Default for Day03Config {
+    fn default() -> Self {
+        Day03Config {
+            group_size: NonZeroUsize::new(3).unwrap(),
+        }
+    }
+}
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|line| Ok(Rucksack::new(parse_rucksack(input, line)?)))
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> Answer {
+        Self::part1_with(rucksacks, &Day03Config::default())
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> Answer {
+        Self::part2_with(rucksacks, &Day03Config::default())
+    }
+}
+
+/// If any rucksack or group a part looks at doesn't share exactly one item, or the last group is
+/// short, the part answers with those anomalies instead of a total.
+impl ConfiguredSolution for Day03 {
+    type Config = Day03Config;
+
+    fn part1_with(rucksacks: &Self::Input<'_>, _config: &Day03Config) -> Answer {
+        total_priority(misplaced_items(rucksacks).ok())
+    }
+
+    fn part2_with(rucksacks: &Self::Input<'_>, config: &Day03Config) -> Answer {
+        total_priority(badges(rucksacks, config.group_size).ok())
+    }
+}
+
+impl StreamSolution for Day03 {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let group_size = Day03Config::default().group_size.get();
+        // None once some rucksack or group has no single item.
+        let mut total = Some(0);
+        let (mut rucksacks, mut group) = (0, ItemSet::all());
+        StreamLines::new(reader).try_for_each(|line| {
+            let rucksack = Rucksack::new(parse_rucksack(line, line)?);
+            rucksacks += 1;
+            let item = match part {
+                Part::One => rucksack.misplaced_item(rucksacks),
+                Part::Two => {
+                    group = group.intersection(rucksack.items());
+                    if !rucksacks.is_multiple_of(group_size) {
+                        return Ok(());
+                    }
+                    badge(
+                        rucksacks / group_size,
+                        mem::replace(&mut group, ItemSet::all()),
+                    )
+                }
+            };
+            total = total
+                .zip(item.ok())
+                .map(|(total, item)| total + priority(item) as u64);
+            Ok(())
+        })?;
+        if part == Part::Two && !rucksacks.is_multiple_of(group_size) {
+            total = None;
+        }
+        Ok(total.map_or(Answer::None, Answer::from))
+    }
+}
+
+fn parse_rucksack<'a>(input: &str, line: &'a str) -> Result<&'a str, ParseError> {
+    if let Some((pos, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::new(
+            input,
+            &line[pos..pos + c.len_utf8()],
+            "expected an item letter",
+        ));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::new(
+            input,
+            line,
+            "expected an even number of items",
+        ));
+    }
+    Ok(line)
+}
+
+fn sum_priorities(items: &[char]) -> u64 {
+    items.iter().map(|&item| priority(item) as u64).sum()
+}
+
+/// Answers with the items' total priority, or with no answer if some anomaly left the part without
+/// items to add up. `rucksack::audit` lists the anomalies.
+fn total_priority(items: Option<Vec<char>>) -> Answer {
+    items.map_or(Answer::None, |items| sum_priorities(&items).into())
+}
+
+impl Generator for Day03 {
+    /// Makes groups of three rucksacks, where each rucksack's compartments share one item and each
+    /// group shares only its badge. Everything else in a group is drawn from one elf's own items.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        let mut rucksacks = Vec::new();
+        for _ in 0..size.div_ceil(3).max(1) {
+            items.shuffle(rng);
+            let badge = items[0];
+            for own_items in items[1..].chunks(17) {
+                let shared = own_items[0];
+                let split = rng.gen_range(2..own_items.len());
+                let mut left = own_items[1..split].to_vec();
+                let mut right = own_items[split..].to_vec();
+                if rng.gen() {
+                    left.push(badge);
+                } else {
+                    right.push(badge);
+                }
+                left.push(shared);
+                right.push(shared);
+                let half = left.len().max(right.len()) + rng.gen_range(0..4);
+                let mut rucksack = fill_compartment(rng, &left, half);
+                rucksack.push_str(&fill_compartment(rng, &right, half));
+                rucksacks.push(rucksack);
+            }
+        }
+        join_lines(rucksacks)
+    }
+}
+
+/// Packs `len` items in a random order, using every one of `items` at least once.
+fn fill_compartment(rng: &mut StdRng, items: &[char], len: usize) -> String {
+    let mut compartment = items.to_vec();
+    while compartment.len() < len {
+        compartment.push(*items.choose(rng).unwrap());
+    }
+    compartment.shuffle(rng);
+    compartment.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rucksack::audit;
+    use common::generate::generate;
+    use common::solution::{solve, solve_with, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day03>(EXAMPLE, Part::One), Ok(Answer::Int(157)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day03>(EXAMPLE, Part::Two), Ok(Answer::Int(70)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day03>(INPUT, Part::One), Ok(Answer::Int(7878)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day03>(INPUT, Part::Two), Ok(Answer::Int(2760)));
+    }
+
+    #[test]
+    fn non_ascii_items_are_errors() {
+        let error = Day03::parse("abمd").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, "م"));
+    }
+
+    #[test]
+    fn anomalies_leave_a_part_without_an_answer() {
+        assert_eq!(
+            solve::<Day03>("abcd\naxay\nabab", Part::One),
+            Ok(Answer::None)
+        );
+        let short_group = EXAMPLE.lines().take(4).collect::<Vec<&str>>().join("\n");
+        assert_eq!(solve::<Day03>(&short_group, Part::Two), Ok(Answer::None));
+        let streamed = Day03::solve_stream(&mut short_group.as_bytes(), Part::Two).unwrap();
+        assert_eq!(streamed, Answer::None);
+        let pairs = Day03Config {
+            group_size: NonZeroUsize::new(2).unwrap(),
+        };
+        assert_eq!(
+            solve_with::<Day03>(EXAMPLE, Part::Two, &pairs),
+            Ok(Answer::None)
+        );
+        let anomalies = audit(&Day03::parse(EXAMPLE).unwrap(), pairs.group_size);
+        assert!(anomalies
+            .iter()
+            .any(|anomaly| anomaly.to_string() == "group 1's rucksacks share f, r, s, F and M"));
+    }
+
+    #[test]
+    fn badges_can_be_shared_by_other_group_sizes() {
+        let config = Day03Config {
+            group_size: NonZeroUsize::new(2).unwrap(),
+        };
+        assert_eq!(
+            solve_with::<Day03>("xaxb\nxcdx\nyeyf\ngyyh", Part::Two, &config),
+            Ok(Answer::from(priority('x') + priority('y')))
+        );
+        let zero = "group_size = 0".parse::<toml::Table>().unwrap();
+        assert!(zero.try_into::<Day03Config>().is_err());
+    }
+
+    /// Compares every item against every other instead of using sets.
+    fn brute_force_priorities(input: &str) -> (u32, u32) {
+        let sacks = input.lines().collect::<Vec<&str>>();
+        let part1 = sacks
+            .iter()
+            .map(|sack| {
+                let (left, right) = sack.split_at(sack.len() / 2);
+                let shared = left.chars().find(|&c| right.contains(c)).unwrap();
+                priority(shared)
+            })
+            .sum();
+        let part2 = sacks
+            .chunks(3)
+            .map(|group| {
+                let badge = group[0]
+                    .chars()
+                    .find(|&c| group[1].contains(c) && group[2].contains(c))
+                    .unwrap();
+                priority(badge)
+            })
+            .sum();
+        (part1, part2)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day03>(seed, size);
+            let (part1, part2) = brute_force_priorities(&input);
+            prop_assert_eq!(solve::<Day03>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day03>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
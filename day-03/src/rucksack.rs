@@ -0,0 +1,287 @@
+use std::{fmt, num::NonZeroUsize};
+
+/// A set of item types, as a bitmask where each type's bit is its priority less one, so that all
+/// 52 types fit in the low bits of a `u64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Every type of item in `items`, which parsing has checked are all letters.
+    pub fn from_items(items: &str) -> ItemSet {
+        ItemSet(
+            items
+                .chars()
+                .fold(0, |mask, item| mask | 1 << (priority(item) - 1)),
+        )
+    }
+
+    /// Every type of item there is, which is what's shared by no rucksacks at all.
+    pub fn all() -> ItemSet {
+        ItemSet((1 << 52) - 1)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The set's only item, if it has exactly one.
+    pub fn single(self) -> Option<char> {
+        if self.0.is_power_of_two() {
+            Some(item(self.0.trailing_zeros() + 1))
+        } else {
+            None
+        }
+    }
+
+    /// The set's items, from the lowest priority up.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        (0..52)
+            .filter(move |bit| self.0 & 1 << bit != 0)
+            .map(|bit| item(bit + 1))
+    }
+}
+
+/// A rucksack's two compartments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    /// Splits `items`, which parsing has checked are an even number of letters, down the middle.
+    pub fn new(items: &str) -> Rucksack {
+        let (left, right) = items.split_at(items.len() / 2);
+        Rucksack {
+            left: ItemSet::from_items(left),
+            right: ItemSet::from_items(right),
+        }
+    }
+
+    /// The items in both compartments, of which there should be exactly one.
+    pub fn shared(&self) -> ItemSet {
+        self.left.intersection(self.right)
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.left.union(self.right)
+    }
+
+    /// The one item in both compartments, or the anomaly if there isn't exactly one, naming the
+    /// rucksack by `number`.
+    pub fn misplaced_item(&self, number: usize) -> Result<char, Anomaly> {
+        let shared = self.shared();
+        shared.single().ok_or(Anomaly::SharedItems {
+            rucksack: number,
+            shared,
+        })
+    }
+}
+
+/// Something about the rucksacks that leaves a part without an answer. Rucksacks and groups are
+/// numbered from 1 in the order the input lists them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anomaly {
+    /// A rucksack whose compartments share no item, or more than one.
+    SharedItems { rucksack: usize, shared: ItemSet },
+    /// A group whose rucksacks all hold no item, or more than one, so that the badge isn't known.
+    Badges { group: usize, shared: ItemSet },
+    /// The last group, which has fewer rucksacks than a group should.
+    IncompleteGroup { group: usize, rucksacks: usize },
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Anomaly::SharedItems { rucksack, shared } => write!(
+                f,
+                "rucksack {}'s compartments share {}",
+                rucksack,
+                describe(*shared)
+            ),
+            Anomaly::Badges { group, shared } => {
+                write!(f, "group {}'s rucksacks share {}", group, describe(*shared))
+            }
+            Anomaly::IncompleteGroup { group, rucksacks } => write!(
+                f,
+                "group {} has only {} rucksack{}",
+                group,
+                rucksacks,
+                if *rucksacks == 1 { "" } else { "s" }
+            ),
+        }
+    }
+}
+
+/// Names the items in a set: "no item", "a" or "a, b and C".
+fn describe(items: ItemSet) -> String {
+    let items = items.items().map(String::from).collect::<Vec<String>>();
+    match items.split_last() {
+        None => "no item".to_owned(),
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// The one item each rucksack's compartments share, or every rucksack that doesn't share exactly
+/// one.
+pub fn misplaced_items(rucksacks: &[Rucksack]) -> Result<Vec<char>, Vec<Anomaly>> {
+    collect_singles(
+        rucksacks
+            .iter()
+            .enumerate()
+            .map(|(i, rucksack)| rucksack.misplaced_item(i + 1)),
+    )
+}
+
+/// The badge of each group of `group_size` rucksacks, which is the one item every rucksack in the
+/// group holds, or every group that doesn't have exactly one, including a last group that's short.
+pub fn badges(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> Result<Vec<char>, Vec<Anomaly>> {
+    let group_size = group_size.get();
+    collect_singles(rucksacks.chunks(group_size).enumerate().map(|(i, group)| {
+        if group.len() < group_size {
+            return Err(Anomaly::IncompleteGroup {
+                group: i + 1,
+                rucksacks: group.len(),
+            });
+        }
+        let shared = group.iter().fold(ItemSet::all(), |shared, rucksack| {
+            shared.intersection(rucksack.items())
+        });
+        badge(i + 1, shared)
+    }))
+}
+
+/// The badge of the group numbered `number`, whose rucksacks all hold `shared`, or the anomaly if
+/// they don't share exactly one item.
+pub fn badge(number: usize, shared: ItemSet) -> Result<char, Anomaly> {
+    shared.single().ok_or(Anomaly::Badges {
+        group: number,
+        shared,
+    })
+}
+
+/// Every anomaly in the rucksacks, for either part.
+pub fn audit(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> Vec<Anomaly> {
+    let mut anomalies = misplaced_items(rucksacks).err().unwrap_or_default();
+    anomalies.extend(badges(rucksacks, group_size).err().unwrap_or_default());
+    return anomalies;
+}
+
+fn collect_singles(
+    items: impl Iterator<Item = Result<char, Anomaly>>,
+) -> Result<Vec<char>, Vec<Anomaly>> {
+    let mut found = Vec::new();
+    let mut anomalies = Vec::new();
+    for item in items {
+        match item {
+            Ok(item) => found.push(item),
+            Err(anomaly) => anomalies.push(anomaly),
+        }
+    }
+    if anomalies.is_empty() {
+        Ok(found)
+    } else {
+        Err(anomalies)
+    }
+}
+
+/// Lowercase items are priorities 1 to 26 and uppercase 27 to 52.
+pub fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        _ => item as u32 - 'A' as u32 + 27,
+    }
+}
+
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_sets_hold_each_type_once() {
+        let items = ItemSet::from_items("aazZAb");
+        assert_eq!(items.len(), 5);
+        assert_eq!(items.items().collect::<String>(), "abzAZ");
+        assert_eq!(items.single(), None);
+        assert_eq!(ItemSet::from_items("ZZ").single(), Some('Z'));
+        assert_eq!(
+            ItemSet::all().items().map(priority).sum::<u32>(),
+            52 * 53 / 2
+        );
+    }
+
+    #[test]
+    fn rucksacks_sharing_no_item_or_several_are_anomalies() {
+        let rucksacks = ["abca", "abcd", "abab", "AxBx"].map(Rucksack::new);
+        assert_eq!(
+            misplaced_items(&rucksacks),
+            Err(vec![
+                Anomaly::SharedItems {
+                    rucksack: 2,
+                    shared: ItemSet::default(),
+                },
+                Anomaly::SharedItems {
+                    rucksack: 3,
+                    shared: ItemSet::from_items("ab"),
+                },
+            ])
+        );
+        assert_eq!(
+            misplaced_items(&rucksacks[..1]).map(|items| items.len()),
+            Ok(1)
+        );
+        assert_eq!(
+            Anomaly::SharedItems {
+                rucksack: 3,
+                shared: ItemSet::from_items("abC"),
+            }
+            .to_string(),
+            "rucksack 3's compartments share a, b and C"
+        );
+    }
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn badges_work_for_any_group_size() {
+        let rucksacks = ["xaxb", "xcdx", "yeyf", "gyyh", "yzzy"].map(Rucksack::new);
+        assert_eq!(badges(&rucksacks[..4], size(2)), Ok(vec!['x', 'y']));
+        assert_eq!(badges(&rucksacks[2..], size(3)), Ok(vec!['y']));
+        assert_eq!(
+            badges(&rucksacks, size(2)),
+            Err(vec![Anomaly::IncompleteGroup {
+                group: 3,
+                rucksacks: 1,
+            }])
+        );
+        assert_eq!(
+            badges(&rucksacks[..1], size(1)),
+            Err(vec![Anomaly::Badges {
+                group: 1,
+                shared: ItemSet::from_items("abx"),
+            }])
+        );
+    }
+}
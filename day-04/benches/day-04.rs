@@ -0,0 +1 @@
+common::bench_day!("day-04", day_04::Day04);
@@ -0,0 +1,141 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::{lines, StreamError, StreamLines},
+    parse::{parse_num_pair, split_pair, ParseError},
+    solution::{Part, Solution, StreamSolution},
+};
+use std::io::BufRead;
+
+type AssignmentPair = ((u16, u16), (u16, u16));
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<AssignmentPair>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input).map(|line| parse_pair(input, line)).collect()
+    }
+
+    fn part1(ranges: &Self::Input<'_>) -> Answer {
+        ranges
+            .iter()
+            .filter(|range| fully_overlaps(range))
+            .count()
+            .into()
+    }
+
+    fn part2(ranges: &Self::Input<'_>) -> Answer {
+        ranges.iter().filter(|range| overlaps(range)).count().into()
+    }
+}
+
+impl StreamSolution for Day04 {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let counts = match part {
+            Part::One => fully_overlaps,
+            Part::Two => overlaps,
+        };
+        let mut count = 0;
+        StreamLines::new(reader).try_for_each(|line| {
+            if counts(&parse_pair(line, line)?) {
+                count += 1;
+            }
+            Ok(())
+        })?;
+        Ok(count.into())
+    }
+}
+
+fn parse_pair(input: &str, line: &str) -> Result<AssignmentPair, ParseError> {
+    let (first, second) = split_pair(input, line, ",")?;
+    Ok((
+        parse_num_pair(input, first, "-")?,
+        parse_num_pair(input, second, "-")?,
+    ))
+}
+
+fn fully_overlaps(range: &AssignmentPair) -> bool {
+    (range.0 .0 >= range.1 .0 && range.0 .1 <= range.1 .1)
+        || (range.1 .0 >= range.0 .0 && range.1 .1 <= range.0 .1)
+}
+
+fn overlaps(range: &AssignmentPair) -> bool {
+    (range.0 .0 >= range.1 .0 && range.0 .0 <= range.1 .1)
+        || (range.0 .1 >= range.1 .0 && range.0 .1 <= range.1 .1)
+        || (range.1 .0 >= range.0 .0 && range.1 .0 <= range.0 .1)
+        || (range.1 .1 >= range.0 .0 && range.1 .1 <= range.0 .1)
+}
+
+impl Generator for Day04 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            (start, rng.gen_range(start..=99))
+        };
+        join_lines((0..size.max(1)).map(|_| {
+            let (first, second) = (range(), range());
+            format!("{}-{},{}-{}", first.0, first.1, second.0, second.1)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day04>(EXAMPLE, Part::One), Ok(Answer::Int(2)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day04>(EXAMPLE, Part::Two), Ok(Answer::Int(4)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day04>(INPUT, Part::One), Ok(Answer::Int(471)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day04>(INPUT, Part::Two), Ok(Answer::Int(888)));
+    }
+
+    /// Lists out every section in each range instead of comparing their ends.
+    fn brute_force_overlaps(input: &str) -> (usize, usize) {
+        let pairs = Day04::parse(input).unwrap();
+        let sections = |(start, end): (u16, u16)| (start..=end).collect::<Vec<u16>>();
+        let (mut contained, mut overlapping) = (0, 0);
+        for (first, second) in pairs {
+            let (first, second) = (sections(first), sections(second));
+            if first.iter().all(|s| second.contains(s)) || second.iter().all(|s| first.contains(s))
+            {
+                contained += 1;
+            }
+            if first.iter().any(|s| second.contains(s)) {
+                overlapping += 1;
+            }
+        }
+        (contained, overlapping)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day04>(seed, size);
+            let (part1, part2) = brute_force_overlaps(&input);
+            prop_assert_eq!(solve::<Day04>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day04>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-05", day_05::Day05);
@@ -0,0 +1,242 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::{blocks, lines},
+    parse::{parse_num, ParseError},
+    solution::Solution,
+};
+use regex::Regex;
+
+type CrateStacks = Vec<Vec<char>>;
+
+type Step = (u16, u16, u16);
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (CrateStacks, Vec<Step>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut sections = blocks(input);
+        let crate_section = sections.next().unwrap_or(input);
+        let step_section = sections.next().ok_or_else(|| {
+            ParseError::missing(
+                input,
+                crate_section,
+                "a blank line between the crates and the moves",
+            )
+        })?;
+        if let Some(extra) = sections.next() {
+            return Err(ParseError::new(
+                input,
+                extra,
+                "expected only crates and moves",
+            ));
+        }
+        let mut crate_lines = lines(crate_section).rev();
+        let stack_count = crate_lines
+            .next()
+            .map_or(0, |numbers| numbers.split_whitespace().count());
+        let mut cubes: CrateStacks = vec![Vec::new(); stack_count];
+        for line in crate_lines {
+            if !line.is_ascii() {
+                return Err(ParseError::new(input, line, "expected only ASCII crates"));
+            }
+            for (i, pos) in (1..line.len()).step_by(4).enumerate() {
+                let spot = &line[pos..pos + 1];
+                let potential_crate_letter = spot.chars().next().unwrap();
+                if potential_crate_letter == ' ' {
+                    continue;
+                }
+                if !potential_crate_letter.is_ascii_alphabetic() || i >= stack_count {
+                    return Err(ParseError::new(input, spot, "expected a crate letter"));
+                }
+                cubes[i].push(potential_crate_letter);
+            }
+        }
+        let re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").expect("Invalid regex");
+        let steps = lines(step_section)
+            .map(|line| {
+                let captures = re.captures(line).ok_or_else(|| {
+                    ParseError::new(input, line, "expected \"move N from N to N\"")
+                })?;
+                let mut moves = [0; 3];
+                for (i, capture) in captures.iter().skip(1).enumerate() {
+                    let capture = capture.unwrap().as_str();
+                    moves[i] = parse_num(input, capture)?;
+                    if i > 0 && !(1..=stack_count).contains(&(moves[i] as usize)) {
+                        return Err(ParseError::new(input, capture, "no such stack"));
+                    }
+                }
+                Ok((moves[0], moves[1], moves[2]))
+            })
+            .collect::<Result<Vec<Step>, ParseError>>()?;
+        Ok((cubes, steps))
+    }
+
+    fn part1((cubes, steps): &Self::Input<'_>) -> Answer {
+        let mut cubes = cubes.clone();
+        for step in steps {
+            for _ in 0..step.0 {
+                let src = (step.1 - 1) as usize;
+                let dest = (step.2 - 1) as usize;
+                let temp = cubes[src].pop();
+                if let Some(val) = temp {
+                    cubes[dest].push(val);
+                }
+            }
+        }
+        get_bottom_row(cubes).into()
+    }
+
+    fn part2((cubes, steps): &Self::Input<'_>) -> Answer {
+        let mut cubes = cubes.clone();
+        for step in steps {
+            let num = step.0 as usize;
+            let src = (step.1 - 1) as usize;
+            let dest = (step.2 - 1) as usize;
+            let src_stack = cubes[src].to_owned();
+            let sections = src_stack.split_at(src_stack.len() - num);
+            cubes[src] = sections
+                .0
+                .iter()
+                .map(|c| c.to_owned())
+                .collect::<Vec<char>>();
+            cubes[dest].append(
+                &mut sections
+                    .1
+                    .iter()
+                    .map(|c| c.to_owned())
+                    .collect::<Vec<char>>(),
+            );
+        }
+        get_bottom_row(cubes).into()
+    }
+}
+
+impl Generator for Day05 {
+    /// Makes stacks of random heights, some of them empty, and moves that never take more crates than a
+    /// stack holds at that point.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let stack_count = rng.gen_range(2..=9);
+        let mut stacks = (0..stack_count)
+            .map(|_| {
+                let height = rng.gen_range(0..=8);
+                (0..height)
+                    .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+                    .collect::<Vec<char>>()
+            })
+            .collect::<CrateStacks>();
+        if stacks.iter().all(|stack| stack.is_empty()) {
+            stacks[0].push('A');
+        }
+        let tallest = stacks.iter().map(|stack| stack.len()).max().unwrap();
+        let mut drawing = (0..tallest)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{}]", c),
+                        None => "   ".to_owned(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>();
+        drawing.push(
+            (1..=stack_count)
+                .map(|number| format!(" {} ", number))
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+
+        let mut steps = Vec::new();
+        for _ in 0..size.max(1) {
+            let filled = (0..stack_count)
+                .filter(|&i| !stacks[i].is_empty())
+                .collect::<Vec<usize>>();
+            let src = *filled.choose(rng).unwrap();
+            let dest = (src + rng.gen_range(1..stack_count)) % stack_count;
+            let num = rng.gen_range(1..=stacks[src].len());
+            let remaining = stacks[src].len() - num;
+            let moved = stacks[src].split_off(remaining);
+            stacks[dest].extend(moved);
+            steps.push(format!("move {} from {} to {}", num, src + 1, dest + 1));
+        }
+        format!("{}\n\n{}", join_lines(drawing), join_lines(steps))
+    }
+}
+
+/// The crate on top of each stack, skipping stacks that have been emptied.
+fn get_bottom_row(boxes: Vec<Vec<char>>) -> String {
+    boxes.iter().filter_map(|row| row.last()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day05>(EXAMPLE, Part::One), Ok(Answer::from("CMZ")));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day05>(EXAMPLE, Part::Two), Ok(Answer::from("MCD")));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(
+            solve::<Day05>(INPUT, Part::One),
+            Ok(Answer::from("FJSRQCFTN"))
+        );
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day05>(INPUT, Part::Two),
+            Ok(Answer::from("CJVLJQPHS"))
+        );
+    }
+
+    #[test]
+    fn emptied_stacks_are_skipped() {
+        let input = "    [B]\n[A] [C]\n 1   2 \n\nmove 1 from 1 to 2";
+        assert_eq!(solve::<Day05>(input, Part::One), Ok(Answer::from("A")));
+        assert_eq!(solve::<Day05>(input, Part::Two), Ok(Answer::from("A")));
+    }
+
+    /// Moves part 2's crates one at a time through a spare stack, which keeps them in order.
+    fn brute_force_part2((stacks, steps): &(CrateStacks, Vec<Step>)) -> String {
+        let mut stacks = stacks.clone();
+        for &(num, src, dest) in steps {
+            let mut spare = Vec::new();
+            for _ in 0..num {
+                spare.push(stacks[src as usize - 1].pop().unwrap());
+            }
+            while let Some(c) = spare.pop() {
+                stacks[dest as usize - 1].push(c);
+            }
+        }
+        stacks.iter().filter_map(|stack| stack.last()).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day05>(seed, size);
+            let crates = Day05::parse(&input).unwrap();
+            prop_assert_eq!(Day05::part2(&crates), Answer::from(brute_force_part2(&crates)));
+        }
+    }
+}
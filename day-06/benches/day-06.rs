@@ -0,0 +1 @@
+common::bench_day!("day-06", day_06::Day06);
@@ -0,0 +1,157 @@
+use common::{
+    answer::Answer,
+    generate::{Generator, Rng, SliceRandom, StdRng},
+    input::StreamError,
+    parse::ParseError,
+    solution::{Part, Solution, StreamSolution},
+};
+use std::{
+    collections::HashSet,
+    io::{BufRead, Read},
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.trim())
+    }
+
+    fn part1(stream: &Self::Input<'_>) -> Answer {
+        find_first_unique_marker(stream, 4).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(stream: &Self::Input<'_>) -> Answer {
+        find_first_unique_marker(stream, 14).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl StreamSolution for Day06 {
+    /// The stream is a single line that could be any length, so this goes through it a byte at a
+    /// time rather than a line at a time, and stops as soon as the marker is found. A stream with no
+    /// marker has no answer.
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let length = match part {
+            Part::One => 4,
+            Part::Two => 14,
+        };
+        let mut window = Vec::with_capacity(length);
+        let mut counts = [0_usize; 256];
+        let mut distinct = 0;
+        let mut position = 0;
+        for byte in reader.bytes() {
+            let byte = byte?;
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+            if window.len() == length {
+                let oldest = window.remove(0) as usize;
+                counts[oldest] -= 1;
+                if counts[oldest] == 0 {
+                    distinct -= 1;
+                }
+            }
+            window.push(byte);
+            counts[byte as usize] += 1;
+            if counts[byte as usize] == 1 {
+                distinct += 1;
+            }
+            position += 1;
+            if distinct == length {
+                return Ok(position.into());
+            }
+        }
+        Ok(Answer::None)
+    }
+}
+
+impl Generator for Day06 {
+    /// Makes a stream from too few letters to hold a start-of-message marker by chance, then writes one
+    /// marker of fourteen different letters somewhere into it.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut letters = ('a'..='z').collect::<Vec<char>>();
+        letters.shuffle(rng);
+        let noise = &letters[..rng.gen_range(4..14)];
+        let mut stream = (0..size.max(14))
+            .map(|_| *noise.choose(rng).unwrap())
+            .collect::<Vec<char>>();
+        let marker_start = rng.gen_range(0..=stream.len() - 14);
+        stream[marker_start..marker_start + 14].copy_from_slice(&letters[..14]);
+        stream.into_iter().collect()
+    }
+}
+
+fn find_first_unique_marker(stream: &str, length: usize) -> Option<usize> {
+    for (i, segment) in stream
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(length)
+        .enumerate()
+    {
+        let char_set = segment.iter().collect::<HashSet<&char>>();
+        if char_set.len() == length {
+            return Some(i + length);
+        }
+    }
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day06>(EXAMPLE, Part::One), Ok(Answer::Int(7)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day06>(EXAMPLE, Part::Two), Ok(Answer::Int(19)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day06>(INPUT, Part::One), Ok(Answer::Int(1929)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day06>(INPUT, Part::Two), Ok(Answer::Int(3298)));
+    }
+
+    #[test]
+    fn streams_without_a_marker_have_no_answer() {
+        assert_eq!(solve::<Day06>("aaaa", Part::One), Ok(Answer::None));
+        let streamed = Day06::solve_stream(&mut "aaaa".as_bytes(), Part::One).unwrap();
+        assert_eq!(streamed, Answer::None);
+    }
+
+    /// Compares every pair of letters in each window instead of using a set.
+    fn brute_force_marker(stream: &str, length: usize) -> usize {
+        let stream = stream.as_bytes();
+        (length..=stream.len())
+            .find(|&end| {
+                let window = &stream[end - length..end];
+                (0..length).all(|i| (i + 1..length).all(|j| window[i] != window[j]))
+            })
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 14..2000usize) {
+            let input = generate::<Day06>(seed, size);
+            prop_assert_eq!(solve::<Day06>(&input, Part::One), Ok(Answer::from(brute_force_marker(&input, 4))));
+            prop_assert_eq!(solve::<Day06>(&input, Part::Two), Ok(Answer::from(brute_force_marker(&input, 14))));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-07", day_07::Day07);
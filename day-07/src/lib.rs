@@ -0,0 +1,341 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::{parse_num, split_pair, ParseError},
+    solution::{ConfiguredSolution, Solution},
+};
+use serde::{Deserialize, Serialize};
+
+pub struct Directory<'a> {
+    name: &'a str,
+    files: Vec<u32>,
+    directories: Vec<Directory<'a>>,
+}
+
+impl<'a> Directory<'a> {
+    fn get_subdir_pos(&self, name: &str) -> Option<usize> {
+        return self
+            .directories
+            .iter()
+            .position(|subdir| subdir.name == name);
+    }
+
+    fn new_dir(name: &str) -> Directory<'_> {
+        let new_dir = Directory {
+            name,
+            files: vec![],
+            directories: vec![],
+        };
+        return new_dir;
+    }
+
+    fn add_subdir(&mut self, new_dir: Directory<'a>) -> usize {
+        self.directories.push(new_dir);
+        return self.directories.len() - 1;
+    }
+
+    fn get_size(&self) -> u32 {
+        return self.files.iter().sum::<u32>()
+            + self.directories.iter().map(|d| d.get_size()).sum::<u32>();
+    }
+}
+
+pub struct Day07;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day07Config {
+    /// Part 1 adds up the directories of at most this size.
+    pub small_directory_limit: u32,
+    pub disk_size: u32,
+    /// How much free space part 2 has to make for the update.
+    pub space_required: u32,
+}
+
+impl Default for Day07Config {
+    fn default() -> Self {
+        Day07Config {
+            small_directory_limit: 100_000,
+            disk_size: 70000000,
+            space_required: 30000000,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    type Input<'a> = Directory<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines = lines(input).collect::<Vec<&str>>();
+        let mut root = Directory {
+            name: "/",
+            files: vec![],
+            directories: vec![],
+        };
+        let mut wd = &mut root;
+        let mut path: Vec<usize> = Vec::new();
+        for line in lines.iter().skip(1) {
+            if let Some(directive) = line.strip_prefix("$") {
+                let directive = directive.trim();
+                let args = directive.split(" ").collect::<Vec<&str>>();
+                let command = args[0];
+                if command == "cd" {
+                    let target = *args
+                        .get(1)
+                        .ok_or_else(|| ParseError::missing(input, line, "a directory"))?;
+                    if target.starts_with("/") {
+                        wd = &mut root;
+                        path = Vec::new();
+                        if target != "/" {
+                            for dir_name in target.split("/").skip(1) {
+                                let subdir_pos = wd.get_subdir_pos(dir_name).ok_or_else(|| {
+                                    ParseError::new(input, dir_name, "no such directory")
+                                })?;
+                                path.push(subdir_pos);
+                                wd = &mut wd.directories[subdir_pos];
+                            }
+                        }
+                    } else if target == ".." {
+                        path.pop();
+                        wd = &mut root;
+                        for pos in &path {
+                            wd = &mut wd.directories[*pos];
+                        }
+                    } else {
+                        let subdir_pos = wd
+                            .get_subdir_pos(target)
+                            .ok_or_else(|| ParseError::new(input, target, "no such directory"))?;
+                        path.push(subdir_pos);
+                        wd = &mut wd.directories[subdir_pos];
+                    }
+                } else if command != "ls" {
+                    return Err(ParseError::new(input, command, "expected cd or ls"));
+                }
+            } else {
+                let (size_or_dir, name) = split_pair(input, line, " ")?;
+                if size_or_dir == "dir" {
+                    let new_dir = Directory::new_dir(name.trim());
+                    wd.add_subdir(new_dir);
+                } else {
+                    let file_size = parse_num(input, size_or_dir)?;
+                    wd.files.push(file_size);
+                }
+            }
+        }
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input<'_>) -> Answer {
+        Self::part1_with(root, &Day07Config::default())
+    }
+
+    fn part2(root: &Self::Input<'_>) -> Answer {
+        Self::part2_with(root, &Day07Config::default())
+    }
+}
+
+impl ConfiguredSolution for Day07 {
+    type Config = Day07Config;
+
+    fn part1_with(root: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        get_total_sizes_under(root, config.small_directory_limit).into()
+    }
+
+    fn part2_with(root: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let used_space = root.get_size();
+        let free_space = config.disk_size.saturating_sub(used_space);
+        let deletion_minimum = config.space_required.saturating_sub(free_space);
+        find_dir_size_closest_to_size(root, deletion_minimum)
+            .unwrap()
+            .into()
+    }
+}
+
+impl Generator for Day07 {
+    /// Makes a random directory tree and explores it depth first, listing each directory before going
+    /// into any of its subdirectories. The disk ends up between 40000000 and 70000000 full, so that
+    /// there's always something to delete to make enough space.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let dir_count = size / 4 + 1;
+        let mut children = vec![Vec::new(); dir_count];
+        for dir in 1..dir_count {
+            children[rng.gen_range(0..dir)].push(dir);
+        }
+        let file_counts = (0..dir_count)
+            .map(|_| rng.gen_range(0..=4))
+            .collect::<Vec<usize>>();
+        let largest_file = 30_000_000 / (file_counts.iter().sum::<usize>() as u32).max(1);
+        let mut files = file_counts
+            .iter()
+            .map(|&count| {
+                (0..count)
+                    .map(|_| {
+                        let largest = largest_file.min(10u32.pow(rng.gen_range(3..=7)));
+                        rng.gen_range(1..=largest)
+                    })
+                    .collect::<Vec<u32>>()
+            })
+            .collect::<Vec<Vec<u32>>>();
+        let used = files.iter().flatten().sum::<u32>();
+        files[0].push(rng.gen_range(40_000_001..=70_000_000) - used);
+
+        let mut terminal = vec!["$ cd /".to_owned()];
+        explore_generated_dir(rng, 0, &mut vec![], &children, &files, &mut terminal);
+        join_lines(terminal)
+    }
+}
+
+/// Writes the commands that list `dir` and then go into each of its subdirectories in turn, which
+/// sometimes go back up through the root rather than with `cd ..`.
+fn explore_generated_dir(
+    rng: &mut StdRng,
+    dir: usize,
+    path: &mut Vec<usize>,
+    children: &[Vec<usize>],
+    files: &[Vec<u32>],
+    terminal: &mut Vec<String>,
+) {
+    let dir_name = |dir: usize| format!("d{}", dir);
+    terminal.push("$ ls".to_owned());
+    let mut entries = children[dir]
+        .iter()
+        .map(|&child| format!("dir {}", dir_name(child)))
+        .chain(
+            files[dir]
+                .iter()
+                .enumerate()
+                .map(|(i, size)| format!("{} f{}.dat", size, i)),
+        )
+        .collect::<Vec<String>>();
+    entries.shuffle(rng);
+    terminal.extend(entries);
+    for &child in &children[dir] {
+        terminal.push(format!("$ cd {}", dir_name(child)));
+        path.push(child);
+        explore_generated_dir(rng, child, path, children, files, terminal);
+        path.pop();
+        if rng.gen_ratio(1, 4) {
+            terminal.push("$ cd /".to_owned());
+            for &dir in path.iter() {
+                terminal.push(format!("$ cd {}", dir_name(dir)));
+            }
+        } else {
+            terminal.push("$ cd ..".to_owned());
+        }
+    }
+}
+
+fn find_dir_size_closest_to_size(dir: &Directory, size: u32) -> Option<u32> {
+    let dir_size = dir.get_size();
+    let mut closest_size = None;
+    if dir_size >= size {
+        closest_size = Some(dir_size);
+    }
+    for subdir in &dir.directories {
+        let subdir_closest = find_dir_size_closest_to_size(subdir, size);
+        closest_size = closest_size.min(subdir_closest).or(closest_size);
+    }
+    return closest_size;
+}
+
+fn get_total_sizes_under(dir: &Directory, max_size: u32) -> u32 {
+    let mut total: u32 = 0;
+    let dir_size = dir.get_size();
+    if dir_size <= max_size {
+        total += dir_size;
+    }
+    for subdir in &dir.directories {
+        total += get_total_sizes_under(subdir, max_size);
+    }
+    return total;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day07>(EXAMPLE, Part::One), Ok(Answer::Int(95437)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day07>(EXAMPLE, Part::Two),
+            Ok(Answer::Int(24933642))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day07>(INPUT, Part::One), Ok(Answer::Int(1667443)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day07>(INPUT, Part::Two), Ok(Answer::Int(8998590)));
+    }
+
+    /// Adds each file's size to every directory on its path, keyed by the whole path, without building
+    /// a tree.
+    fn brute_force_sizes(input: &str) -> (u32, u32) {
+        let mut sizes: HashMap<Vec<&str>, u32> = HashMap::new();
+        let mut path = Vec::new();
+        for line in input.lines() {
+            match line.split(' ').collect::<Vec<&str>>()[..] {
+                ["$", "cd", "/"] => path.clear(),
+                ["$", "cd", ".."] => {
+                    path.pop();
+                }
+                ["$", "cd", dir] => path.push(dir),
+                ["$", "ls"] | ["dir", _] => {}
+                [size, _] => {
+                    for depth in 0..=path.len() {
+                        *sizes.entry(path[..depth].to_vec()).or_default() +=
+                            size.parse::<u32>().unwrap();
+                    }
+                }
+                _ => panic!("unexpected line {:?}", line),
+            }
+        }
+        let part1 = sizes.values().filter(|&&size| size <= 100_000).sum();
+        let needed = 30000000 - (70000000 - sizes[&vec![]]);
+        let part2 = *sizes
+            .values()
+            .filter(|&&size| size >= needed)
+            .min()
+            .unwrap();
+        (part1, part2)
+    }
+
+    #[test]
+    fn cd_into_an_unlisted_directory_is_an_error() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd b";
+        assert!(Day07::parse(input).is_err());
+    }
+
+    #[test]
+    fn cd_up_from_an_absolute_path_returns_to_its_parent() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n100 f\n$ cd /a\n$ cd ..\n$ ls\n200 g";
+        assert_eq!(solve::<Day07>(input, Part::One), Ok(Answer::Int(100 + 300)));
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day07>(seed, size);
+            let (part1, part2) = brute_force_sizes(&input);
+            prop_assert_eq!(solve::<Day07>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day07>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-08", day_08::Day08);
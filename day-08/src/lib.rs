@@ -0,0 +1,303 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::lines,
+    parse::ParseError,
+    solution::Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+type ScoreComponents = HashMap<(usize, usize), u16>;
+
+struct ForestSurvey {
+    visible_trees: HashSet<(usize, usize)>,
+    left_score_components: ScoreComponents,
+    right_score_components: ScoreComponents,
+    down_score_components: ScoreComponents,
+    up_score_components: ScoreComponents,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let trees = lines(input)
+            .map(|line| {
+                let line = line.trim();
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10).map(|height| height as u8).ok_or_else(|| {
+                            ParseError::new(
+                                input,
+                                &line[i..i + c.len_utf8()],
+                                "expected a tree height",
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<u8>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<u8>>, ParseError>>()?;
+        let lines = lines(input);
+        for (row, line) in trees.iter().zip(lines) {
+            if row.len() != trees[0].len() {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("expected a row of {} trees", trees[0].len()),
+                ));
+            }
+        }
+        Ok(trees)
+    }
+
+    fn part1(trees: &Self::Input<'_>) -> Answer {
+        survey_forest(trees).visible_trees.len().into()
+    }
+
+    fn part2(trees: &Self::Input<'_>) -> Answer {
+        let survey = survey_forest(trees);
+        let mut highest_score = 0;
+        for i in 0..trees.len() {
+            for j in 0..trees[0].len() {
+                let left = *survey.left_score_components.get(&(i, j)).unwrap_or(&0);
+                let right = *survey.right_score_components.get(&(i, j)).unwrap_or(&0);
+                let down = *survey.down_score_components.get(&(i, j)).unwrap_or(&0);
+                let up = *survey.up_score_components.get(&(i, j)).unwrap_or(&0);
+                let score = (left as u32) * (right as u32) * (down as u32) * (up as u32);
+                if score > highest_score {
+                    highest_score = score;
+                }
+            }
+        }
+        highest_score.into()
+    }
+}
+
+impl Generator for Day08 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(1);
+        join_lines((0..side).map(|_| {
+            (0..side)
+                .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                .collect()
+        }))
+    }
+}
+
+fn survey_forest(trees: &[Vec<u8>]) -> ForestSurvey {
+    let mut visible_trees: HashSet<(usize, usize)> = HashSet::new();
+    let mut left_score_components: ScoreComponents = HashMap::new();
+    let mut right_score_components: ScoreComponents = HashMap::new();
+    let mut down_score_components: ScoreComponents = HashMap::new();
+    let mut up_score_components: ScoreComponents = HashMap::new();
+    for (i, tree_line) in trees.iter().enumerate() {
+        let mut tallest: Option<u8> = None;
+        let mut distance_to_nearest_tree_of_at_least_height: Vec<Option<u16>> = vec![None; 10];
+        for (j, &tree) in tree_line.iter().enumerate() {
+            tallest = Some(update_visible_trees(
+                tallest,
+                tree,
+                &mut visible_trees,
+                i,
+                j,
+            ));
+            update_score_component(
+                &mut left_score_components,
+                &mut distance_to_nearest_tree_of_at_least_height,
+                tree,
+                i,
+                j,
+                j as u16,
+            );
+        }
+        let mut tallest: Option<u8> = None;
+        let mut distance_to_nearest_tree_of_at_least_height: Vec<Option<u16>> = vec![None; 10];
+        for (j_rev, &tree) in tree_line.iter().rev().enumerate() {
+            let j = tree_line.len() - j_rev - 1;
+            tallest = Some(update_visible_trees(
+                tallest,
+                tree,
+                &mut visible_trees,
+                i,
+                j,
+            ));
+            update_score_component(
+                &mut right_score_components,
+                &mut distance_to_nearest_tree_of_at_least_height,
+                tree,
+                i,
+                j,
+                j_rev as u16,
+            );
+        }
+    }
+    for j in 0..trees[0].len() {
+        let tree_line = trees
+            .iter()
+            .map(|tree_row| tree_row[j])
+            .collect::<Vec<u8>>();
+        let mut tallest: Option<u8> = None;
+        let mut distance_to_nearest_tree_of_at_least_height: Vec<Option<u16>> = vec![None; 10];
+        for (i, &tree) in tree_line.iter().enumerate() {
+            tallest = Some(update_visible_trees(
+                tallest,
+                tree,
+                &mut visible_trees,
+                i,
+                j,
+            ));
+            update_score_component(
+                &mut down_score_components,
+                &mut distance_to_nearest_tree_of_at_least_height,
+                tree,
+                i,
+                j,
+                i as u16,
+            );
+        }
+        let mut tallest: Option<u8> = None;
+        let mut distance_to_nearest_tree_of_at_least_height: Vec<Option<u16>> = vec![None; 10];
+        for (i_rev, &tree) in tree_line.iter().rev().enumerate() {
+            let i = tree_line.len() - i_rev - 1;
+            tallest = Some(update_visible_trees(
+                tallest,
+                tree,
+                &mut visible_trees,
+                i,
+                j,
+            ));
+            update_score_component(
+                &mut up_score_components,
+                &mut distance_to_nearest_tree_of_at_least_height,
+                tree,
+                i,
+                j,
+                i_rev as u16,
+            );
+        }
+    }
+
+    ForestSurvey {
+        visible_trees,
+        left_score_components,
+        right_score_components,
+        down_score_components,
+        up_score_components,
+    }
+}
+
+fn update_visible_trees(
+    tallest: Option<u8>,
+    tree: u8,
+    visible_trees: &mut HashSet<(usize, usize)>,
+    i: usize,
+    j: usize,
+) -> u8 {
+    if let Some(tallest_val) = tallest {
+        if tree > tallest_val {
+            visible_trees.insert((i, j));
+        } else {
+            return tallest_val;
+        }
+    } else {
+        visible_trees.insert((i, j));
+    }
+    return tree;
+}
+
+fn update_score_component(
+    score_component: &mut HashMap<(usize, usize), u16>,
+    distance_to_nearest_tree_of_at_least_height: &mut [Option<u16>],
+    tree: u8,
+    i: usize,
+    j: usize,
+    default: u16,
+) {
+    let comp = distance_to_nearest_tree_of_at_least_height[tree as usize].unwrap_or(default);
+    score_component.insert((i, j), comp);
+    let (at_most_height, taller) =
+        distance_to_nearest_tree_of_at_least_height.split_at_mut((tree + 1) as usize);
+    for distance in at_most_height {
+        *distance = Some(1);
+    }
+    for distance in taller.iter_mut().flatten() {
+        *distance += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day08>(EXAMPLE, Part::One), Ok(Answer::Int(21)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day08>(EXAMPLE, Part::Two), Ok(Answer::Int(8)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day08>(INPUT, Part::One), Ok(Answer::Int(1538)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day08>(INPUT, Part::Two), Ok(Answer::Int(496125)));
+    }
+
+    /// Looks out from every tree in every direction instead of sweeping along each line.
+    fn brute_force_survey(trees: &[Vec<u8>]) -> (usize, u32) {
+        let (height, width) = (trees.len() as i32, trees[0].len() as i32);
+        let mut visible = 0;
+        let mut best_score = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let tree = trees[y as usize][x as usize];
+                let mut seen_from_outside = false;
+                let mut score = 1;
+                for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                    let (mut cx, mut cy) = (x + dx, y + dy);
+                    let mut distance = 0;
+                    let mut blocked = false;
+                    while (0..width).contains(&cx) && (0..height).contains(&cy) {
+                        distance += 1;
+                        if trees[cy as usize][cx as usize] >= tree {
+                            blocked = true;
+                            break;
+                        }
+                        (cx, cy) = (cx + dx, cy + dy);
+                    }
+                    seen_from_outside |= !blocked;
+                    score *= distance;
+                }
+                if seen_from_outside {
+                    visible += 1;
+                }
+                best_score = best_score.max(score);
+            }
+        }
+        (visible, best_score)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..60usize) {
+            let input = generate::<Day08>(seed, size);
+            let (part1, part2) = brute_force_survey(&Day08::parse(&input).unwrap());
+            prop_assert_eq!(solve::<Day08>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day08>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
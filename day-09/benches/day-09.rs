@@ -0,0 +1 @@
+common::bench_day!("day-09", day_09::Day09);
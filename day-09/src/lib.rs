@@ -0,0 +1,187 @@
+use common::{
+    answer::Answer,
+    direction::CardinalDirection,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::{parse_num, split_pair, ParseError},
+    pos::MapPosComp,
+    solution::{Part, Solution},
+    visualize::{Frame, Observer},
+};
+use std::collections::HashSet;
+
+const ADJACENT_SPACE_OFFSETS: [(i8, i8); 9] = [
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (0, 0),
+];
+
+type RopePosComp = i16;
+
+type RopeSegmentPos = (RopePosComp, RopePosComp);
+
+fn direction_from_str(s: &str) -> Option<CardinalDirection> {
+    match s {
+        "U" => Some(CardinalDirection::North),
+        "D" => Some(CardinalDirection::South),
+        "L" => Some(CardinalDirection::West),
+        "R" => Some(CardinalDirection::East),
+        _ => None,
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<(CardinalDirection, u8)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let (direction, distance) = split_pair(input, line, " ")?;
+                let direction = direction_from_str(direction).ok_or_else(|| {
+                    ParseError::new(input, direction, "expected a direction: U, D, L or R")
+                })?;
+                let distance = parse_num(input, distance.trim())?;
+                Ok((direction, distance))
+            })
+            .collect()
+    }
+
+    fn part1(movements: &Self::Input<'_>) -> Answer {
+        Self::watch(movements, Part::One, &mut ())
+    }
+
+    fn part2(movements: &Self::Input<'_>) -> Answer {
+        Self::watch(movements, Part::Two, &mut ())
+    }
+
+    fn watch(movements: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        let length = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        create_and_simulate_rope(length, movements, observer)
+            .len()
+            .into()
+    }
+}
+
+impl Generator for Day09 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        join_lines((0..size.max(1)).map(|_| {
+            let direction = ["U", "D", "L", "R"].choose(rng).unwrap();
+            format!("{} {}", direction, rng.gen_range(1..20))
+        }))
+    }
+}
+
+/// Moves the rope's head through every movement, one step at a time, returning every position the tail visited.
+fn create_and_simulate_rope(
+    length: usize,
+    movements: &Vec<(CardinalDirection, u8)>,
+    observer: &mut dyn Observer,
+) -> HashSet<RopeSegmentPos> {
+    let mut rope = vec![(0 as RopePosComp, 0 as RopePosComp); length];
+    let mut tail_visited = HashSet::new();
+    tail_visited.insert(rope[length - 1]);
+    for (direction, distance) in movements {
+        let movement_offset = direction.get_offset();
+        for _ in 0..*distance {
+            rope[0] = (
+                rope[0].0 + movement_offset.0 as RopePosComp,
+                rope[0].1 + movement_offset.1 as RopePosComp,
+            );
+            for i in 1..length {
+                let leading_segment = rope[i - 1];
+                let tailing_segment = rope[i];
+                if !segments_are_connected(leading_segment, tailing_segment) {
+                    rope[i] = determine_next_tailing_segment_pos(leading_segment, tailing_segment);
+                    if i == length - 1 {
+                        tail_visited.insert(rope[i]);
+                    }
+                }
+            }
+            observer.step(&|| draw_rope(&rope, &tail_visited));
+        }
+    }
+    return tail_visited;
+}
+
+/// Draws the places the tail has been as `#`, under the rope with its head as `H` and the
+/// knots behind it numbered.
+fn draw_rope(rope: &[RopeSegmentPos], tail_visited: &HashSet<RopeSegmentPos>) -> Frame {
+    let to_map_pos = |pos: &RopeSegmentPos| (pos.0 as MapPosComp, pos.1 as MapPosComp);
+    let visited = tail_visited.iter().map(|pos| (to_map_pos(pos), '#'));
+    let knots = rope.iter().enumerate().rev().map(|(i, pos)| {
+        let tile = match i {
+            0 => 'H',
+            _ => char::from_digit(i as u32 % 10, 10).unwrap(),
+        };
+        (to_map_pos(pos), tile)
+    });
+    Frame::from_points(visited.chain(knots), '.')
+}
+
+fn segments_are_connected(seg_1: RopeSegmentPos, seg_2: RopeSegmentPos) -> bool {
+    for offset in ADJACENT_SPACE_OFFSETS {
+        let offset_pos = (
+            seg_1.0 + offset.0 as RopePosComp,
+            seg_1.1 + offset.1 as RopePosComp,
+        );
+        if seg_2 == offset_pos {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn determine_next_tailing_segment_pos(
+    leading_segment: RopeSegmentPos,
+    tailing_segment: RopeSegmentPos,
+) -> RopeSegmentPos {
+    let total_offset = (
+        leading_segment.0 - tailing_segment.0,
+        leading_segment.1 - tailing_segment.1,
+    );
+    let clamped_offset = (total_offset.0.clamp(-1, 1), total_offset.1.clamp(-1, 1));
+    return (
+        tailing_segment.0 + clamped_offset.0,
+        tailing_segment.1 + clamped_offset.1,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day09>(EXAMPLE, Part::One), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day09>(EXAMPLE, Part::Two), Ok(Answer::Int(1)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day09>(INPUT, Part::One), Ok(Answer::Int(6067)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day09>(INPUT, Part::Two), Ok(Answer::Int(2471)));
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-10", day_10::Day10);
@@ -0,0 +1,331 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::{lines, StreamError, StreamLines},
+    parse::{parse_num, ParseError},
+    solution::{Part, Solution, StreamSolution},
+};
+use std::io::BufRead;
+
+type CPUVal = i64;
+
+const CYCLES_OF_INTEREST: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+const SCREEN_DIMENSIONS: (usize, usize) = (40, 6);
+
+pub enum CPUOp {
+    Nop,
+    AddX(CPUVal),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<CPUOp>;
+
+    /// Also checks that X stays within a `CPUVal`, so that the program can be run without
+    /// overflowing.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut x: CPUVal = 1;
+        lines(input)
+            .map(|line| {
+                let instruction = parse_instruction(input, line)?;
+                if let CPUOp::AddX(arg) = instruction {
+                    x = x.checked_add(arg).ok_or_else(|| x_overflow(input, line))?;
+                }
+                Ok(instruction)
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        let x_cycles = compute_x_cycles(instructions);
+        calculate_signal_strengths_sum(&x_cycles, &CYCLES_OF_INTEREST).into()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        let x_cycles = compute_x_cycles(instructions);
+        render_screen(SCREEN_DIMENSIONS, &x_cycles).into()
+    }
+}
+
+impl StreamSolution for Day10 {
+    /// Follows X one cycle at a time instead of keeping its value for every cycle.
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let mut monitor = CpuMonitor::new();
+        StreamLines::new(reader).try_for_each(|line| {
+            monitor
+                .execute(&parse_instruction(line, line)?)
+                .ok_or_else(|| x_overflow(line, line))?;
+            Ok(())
+        })?;
+        let monitor = monitor.finish();
+        match part {
+            Part::One => Ok(monitor.signal_strengths_sum.into()),
+            Part::Two => Ok(monitor.screen.into()),
+        }
+    }
+}
+
+fn parse_instruction(input: &str, line: &str) -> Result<CPUOp, ParseError> {
+    let parts = line.split(" ").collect::<Vec<&str>>();
+    let op = parts[0].trim();
+    match op {
+        "noop" => Ok(CPUOp::Nop),
+        "addx" => {
+            let arg = parts
+                .get(1)
+                .ok_or_else(|| ParseError::missing(input, line, "an argument"))?;
+            Ok(CPUOp::AddX(parse_num(input, arg.trim())?))
+        }
+        _ => Err(ParseError::new(input, op, "expected noop or addx")),
+    }
+}
+
+fn x_overflow(input: &str, line: &str) -> ParseError {
+    ParseError::new(
+        input,
+        line.trim(),
+        format!("X goes outside {} to {}", CPUVal::MIN, CPUVal::MAX),
+    )
+}
+
+/// Runs a program, adding up the signal strengths and drawing the screen as each cycle passes.
+struct CpuMonitor {
+    x: CPUVal,
+    cycle: usize,
+    /// Wide enough that no six signal strengths can overflow it.
+    signal_strengths_sum: i128,
+    screen: Vec<Vec<bool>>,
+}
+
+impl CpuMonitor {
+    fn new() -> CpuMonitor {
+        let (width, height) = SCREEN_DIMENSIONS;
+        CpuMonitor {
+            x: 1,
+            cycle: 0,
+            signal_strengths_sum: 0,
+            screen: vec![vec![false; width]; height],
+        }
+    }
+
+    /// Runs one instruction, or returns `None` if it takes X past what a `CPUVal` holds.
+    fn execute(&mut self, instruction: &CPUOp) -> Option<()> {
+        match instruction {
+            CPUOp::Nop => self.tick(),
+            CPUOp::AddX(arg) => {
+                self.tick();
+                self.tick();
+                self.x = self.x.checked_add(*arg)?;
+            }
+        }
+        Some(())
+    }
+
+    /// Lets the CPU idle until every cycle of interest has passed and the screen is drawn.
+    fn finish(mut self) -> CpuMonitor {
+        let (width, height) = SCREEN_DIMENSIONS;
+        while self.cycle < width * height {
+            self.tick();
+        }
+        self
+    }
+
+    fn tick(&mut self) {
+        self.cycle += 1;
+        if CYCLES_OF_INTEREST.contains(&self.cycle) {
+            self.signal_strengths_sum += self.cycle as i128 * self.x as i128;
+        }
+        let (width, height) = SCREEN_DIMENSIONS;
+        let pixel = self.cycle - 1;
+        if pixel < width * height {
+            let column = pixel % width;
+            self.screen[pixel / width][column] = self.x.abs_diff(column as CPUVal) <= 1;
+        }
+    }
+}
+
+impl Generator for Day10 {
+    /// Makes a program that runs for at least the 240 cycles it takes to draw the screen, keeping X
+    /// around the screen's columns.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut program = Vec::new();
+        let mut cycles = 0;
+        let mut x: CPUVal = 1;
+        while cycles < size.max(240) {
+            if rng.gen_ratio(1, 3) {
+                program.push("noop".to_owned());
+                cycles += 1;
+                continue;
+            }
+            let mut arg = rng.gen_range(-9..=9);
+            if !(-5..=45).contains(&(x + arg)) {
+                arg = -arg;
+            }
+            x += arg;
+            program.push(format!("addx {}", arg));
+            cycles += 2;
+        }
+        join_lines(program)
+    }
+}
+
+fn compute_x_cycles(instructions: &[CPUOp]) -> Vec<CPUVal> {
+    let mut x_cycles = vec![1];
+    for instruction in instructions {
+        let cur_x = *x_cycles.last().unwrap();
+        match instruction {
+            CPUOp::Nop => x_cycles.push(cur_x),
+            CPUOp::AddX(arg) => {
+                x_cycles.push(cur_x);
+                x_cycles.push(cur_x + arg);
+            }
+        }
+    }
+    x_cycles
+}
+
+fn calculate_signal_strengths_sum(x_cycles: &[CPUVal], cycles_of_interest: &[usize]) -> i128 {
+    cycles_of_interest
+        .iter()
+        .map(|&cycle| {
+            let cur_x = x_cycles[cycle - 1];
+            cycle as i128 * cur_x as i128
+        })
+        .sum::<i128>()
+}
+
+fn render_screen(screen_dimensions: (usize, usize), x_cycles: &[CPUVal]) -> Vec<Vec<bool>> {
+    let mut rows = Vec::new();
+    let mut cycle = 0;
+    for _ in 0..screen_dimensions.1 {
+        let mut row = Vec::new();
+        for column in 0..screen_dimensions.0 {
+            row.push(x_cycles[cycle].abs_diff(column as CPUVal) <= 1);
+            cycle += 1;
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    const EXAMPLE_SCREEN: [&str; 6] = [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ];
+
+    const INPUT_SCREEN: [&str; 6] = [
+        "####.#..#.###..#..#.####.###..#..#.####.",
+        "#....#.#..#..#.#..#.#....#..#.#..#....#.",
+        "###..##...#..#.####.###..#..#.#..#...#..",
+        "#....#.#..###..#..#.#....###..#..#..#...",
+        "#....#.#..#.#..#..#.#....#....#..#.#....",
+        "####.#..#.#..#.#..#.####.#.....##..####.",
+    ];
+
+    fn screen(rows: &[&str]) -> Answer {
+        Answer::Bitmap(
+            rows.iter()
+                .map(|row| row.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day10>(EXAMPLE, Part::One), Ok(Answer::Int(13140)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day10>(EXAMPLE, Part::Two),
+            Ok(screen(&EXAMPLE_SCREEN))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day10>(INPUT, Part::One), Ok(Answer::Int(14560)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day10>(INPUT, Part::Two), Ok(screen(&INPUT_SCREEN)));
+    }
+
+    #[test]
+    fn x_and_the_signal_can_grow_past_an_i32() {
+        let input = join_lines((0..5000).map(|_| "addx 1000000".to_owned()));
+        let (part1, part2) = brute_force_cpu(&input);
+        assert_eq!(solve::<Day10>(&input, Part::One), Ok(Answer::from(part1)));
+        assert_eq!(
+            Day10::solve_stream(&mut input.as_bytes(), Part::One).unwrap(),
+            Answer::from(part1)
+        );
+        assert_eq!(solve::<Day10>(&input, Part::Two), Ok(Answer::from(part2)));
+    }
+
+    #[test]
+    fn x_past_an_i64_is_an_error() {
+        // X starts at 1.
+        let input = format!("noop\naddx {}\naddx 1", i64::MAX - 1);
+        assert_eq!(Day10::parse(&input).err().unwrap().line, 3);
+        let streamed = Day10::solve_stream(&mut input.as_bytes(), Part::One).unwrap_err();
+        assert!(matches!(streamed, StreamError::Parse(err) if err.line == 3));
+        let input = format!("addx {}\naddx -2\naddx -1", i64::MIN + 1);
+        assert_eq!(Day10::parse(&input).err().unwrap().line, 3);
+        assert!(Day10::parse(&input[..input.rfind('\n').unwrap()]).is_ok());
+    }
+
+    /// Runs the program one cycle at a time, checking the signal and drawing a pixel as it goes.
+    fn brute_force_cpu(input: &str) -> (CPUVal, Vec<Vec<bool>>) {
+        let mut x = 1;
+        let mut cycle = 0;
+        let mut signal = 0;
+        let mut screen = vec![vec![false; 40]; 6];
+        let mut tick = |x: CPUVal| {
+            cycle += 1;
+            if cycle % 40 == 20 && cycle <= 220 {
+                signal += cycle * x;
+            }
+            if cycle <= 240 {
+                let column = (cycle - 1) % 40;
+                screen[(cycle as usize - 1) / 40][column as usize] = (x - column).abs() <= 1;
+            }
+        };
+        for line in input.lines() {
+            tick(x);
+            if let Some(arg) = line.strip_prefix("addx ") {
+                tick(x);
+                x += arg.parse::<CPUVal>().unwrap();
+            }
+        }
+        (signal, screen)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..400usize) {
+            let input = generate::<Day10>(seed, size);
+            let (part1, part2) = brute_force_cpu(&input);
+            prop_assert_eq!(solve::<Day10>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day10>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-11", day_11::Day11);
@@ -0,0 +1,244 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::{blocks, lines},
+    parse::{parse_num, split_pair, ParseError},
+    solution::Solution,
+};
+use std::collections::VecDeque;
+
+#[derive(Clone)]
+enum MonkeyOp {
+    Multiply,
+    Add,
+}
+
+impl MonkeyOp {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "*" => Some(MonkeyOp::Multiply),
+            "+" => Some(MonkeyOp::Add),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum MonkeyOpVal {
+    Old,
+    Val(u16),
+}
+
+impl MonkeyOpVal {
+    fn from_str(input: &str, s: &str) -> Result<Self, ParseError> {
+        match s {
+            "old" => Ok(MonkeyOpVal::Old),
+            _ => Ok(MonkeyOpVal::Val(parse_num(input, s)?)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct MonkeyAction {
+    op: MonkeyOp,
+    val: MonkeyOpVal,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    action: MonkeyAction,
+    items: VecDeque<u64>,
+    test_val: u64,
+    dest_if_true: usize,
+    dest_if_false: usize,
+}
+
+impl Monkey {
+    fn from_str(input: &str, monkey_content: &str) -> Result<Self, ParseError> {
+        let mut lines = lines(monkey_content);
+        let mut next_field = |prefix: &str| -> Result<&str, ParseError> {
+            let line = lines.next().ok_or_else(|| {
+                ParseError::missing(input, monkey_content, &format!("{:?}", prefix))
+            })?;
+            line.trim_start()
+                .strip_prefix(prefix)
+                .ok_or_else(|| ParseError::new(input, line, format!("expected {:?}", prefix)))
+        };
+        next_field("Monkey ")?;
+        let items = next_field("Starting items: ")?
+            .split(",")
+            .map(|item| parse_num(input, item.trim()))
+            .collect::<Result<VecDeque<u64>, ParseError>>()?;
+        let action = next_field("Operation: new = old ")?;
+        let (op, val) = split_pair(input, action, " ")?;
+        let action = MonkeyAction {
+            op: MonkeyOp::from_str(op)
+                .ok_or_else(|| ParseError::new(input, op, "expected * or +"))?,
+            val: MonkeyOpVal::from_str(input, val)?,
+        };
+        let test_val = parse_num(input, next_field("Test: divisible by ")?)?;
+        let dest_if_true = parse_num(input, next_field("If true: throw to monkey ")?)?;
+        let dest_if_false = parse_num(input, next_field("If false: throw to monkey ")?)?;
+        Ok(Monkey {
+            action,
+            items,
+            test_val,
+            dest_if_true,
+            dest_if_false,
+        })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let monkeys_content = blocks(input).collect::<Vec<&str>>();
+        let monkeys = monkeys_content
+            .iter()
+            .map(|monkey_content| Monkey::from_str(input, monkey_content))
+            .collect::<Result<Vec<Monkey>, ParseError>>()?;
+        for (monkey, monkey_content) in monkeys.iter().zip(monkeys_content) {
+            if monkey.dest_if_true.max(monkey.dest_if_false) >= monkeys.len() {
+                return Err(ParseError::new(
+                    input,
+                    monkey_content,
+                    "throws to a monkey that doesn't exist",
+                ));
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Answer {
+        perform_monkey_business(20, &mut monkeys.clone(), 3).into()
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> Answer {
+        perform_monkey_business(10000, &mut monkeys.clone(), 1).into()
+    }
+}
+
+impl Generator for Day11 {
+    /// Makes four to eight monkeys, each testing a different prime and throwing to two other monkeys.
+    /// Only one of them squares its items, so worry levels stay well inside a u64.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let monkey_count = (size / 4).clamp(4, 8);
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        primes.shuffle(rng);
+        let squarer = rng.gen_range(0..monkey_count);
+        let monkeys = (0..monkey_count).map(|i| {
+            let items = (0..rng.gen_range(1..=5))
+                .map(|_| rng.gen_range(50..100).to_string())
+                .collect::<Vec<String>>();
+            let operation = if i == squarer {
+                "* old".to_owned()
+            } else if rng.gen() {
+                format!("* {}", rng.gen_range(2..20))
+            } else {
+                format!("+ {}", rng.gen_range(1..9))
+            };
+            let mut others = (0..monkey_count)
+                .filter(|&other| other != i)
+                .collect::<Vec<usize>>();
+            others.shuffle(rng);
+            join_lines([
+                format!("Monkey {}:", i),
+                format!("  Starting items: {}", items.join(", ")),
+                format!("  Operation: new = old {}", operation),
+                format!("  Test: divisible by {}", primes[i]),
+                format!("    If true: throw to monkey {}", others[0]),
+                format!("    If false: throw to monkey {}", others[1]),
+            ])
+        });
+        monkeys.collect::<Vec<String>>().join("\n\n")
+    }
+}
+
+fn perform_monkey_business(rounds: usize, monkeys: &mut [Monkey], worry_decay_factor: u8) -> u64 {
+    let mut inspection_counts = vec![0; monkeys.len()];
+    let monkey_multiple = calculate_monkey_lcm(monkeys);
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let mut thrown_items: Vec<Vec<u64>> = vec![vec![]; monkeys.len()];
+            let monkey = &mut monkeys[i];
+            while !monkey.items.is_empty() {
+                inspection_counts[i] += 1;
+                let mut item = monkey.items.pop_front().unwrap();
+                let mod_val = match monkey.action.val {
+                    MonkeyOpVal::Old => item,
+                    MonkeyOpVal::Val(v) => v as u64,
+                };
+                item = match monkey.action.op {
+                    MonkeyOp::Multiply => item * mod_val,
+                    MonkeyOp::Add => item + mod_val,
+                };
+                item %= monkey_multiple;
+                item /= worry_decay_factor as u64;
+                if item.is_multiple_of(monkey.test_val) {
+                    thrown_items[monkey.dest_if_true].push(item);
+                } else {
+                    thrown_items[monkey.dest_if_false].push(item);
+                }
+            }
+            for (j, items) in thrown_items.iter().enumerate() {
+                monkeys[j].items.extend(items);
+            }
+        }
+    }
+    inspection_counts.sort();
+    return inspection_counts.iter().rev().take(2).product::<u64>();
+}
+
+fn calculate_monkey_lcm(monkeys: &[Monkey]) -> u64 {
+    monkeys
+        .iter()
+        .map(|monkey| monkey.test_val)
+        .fold(1, |acc, val| {
+            let mut a = acc;
+            let mut b = val;
+            while b != 0 {
+                let temp = b;
+                b = a % b;
+                a = temp;
+            }
+            acc * val / a
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day11>(EXAMPLE, Part::One), Ok(Answer::Int(10605)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day11>(EXAMPLE, Part::Two),
+            Ok(Answer::Int(2713310158))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day11>(INPUT, Part::One), Ok(Answer::Int(78960)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day11>(INPUT, Part::Two),
+            Ok(Answer::Int(14561971968))
+        );
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-12", day_12::Day12);
@@ -0,0 +1,214 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    grid::{Grid, NEIGHBORS_4},
+    parse::ParseError,
+    pos::MapPos,
+    search::bfs,
+    solution::Solution,
+};
+use std::collections::HashSet;
+
+const START_CHAR: char = 'S';
+const END_CHAR: char = 'E';
+
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: MapPos,
+    end: MapPos,
+    lowest_points: HashSet<MapPos>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let tiles = Grid::parse_dense(input, input, |c| match c {
+            START_CHAR | END_CHAR | 'a'..='z' => Some(c),
+            _ => None,
+        })?;
+        let find = |tile: char| tiles.iter().find(|(_, &c)| c == tile).map(|(pos, _)| pos);
+        let end =
+            find(END_CHAR).ok_or_else(|| ParseError::missing(input, input, "an end position E"))?;
+        let start = find(START_CHAR)
+            .ok_or_else(|| ParseError::missing(input, input, "a start position S"))?;
+        let lowest_points = tiles
+            .iter()
+            .filter(|(_, &c)| c == 'a')
+            .map(|(pos, _)| pos)
+            .collect();
+        let heights = tiles.map(|_, &c| match c {
+            START_CHAR => 1,
+            END_CHAR => 26,
+            _ => c as u8 - 96,
+        });
+        Ok(HeightMap {
+            heights,
+            start,
+            end,
+            lowest_points,
+        })
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        calculate_min_steps_to_end(&map.heights, HashSet::from([map.start]), map.end).into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        calculate_min_steps_to_end(&map.heights, map.lowest_points.clone(), map.end).into()
+    }
+}
+
+impl Generator for Day12 {
+    /// Makes a map of random heights with one climbable path snaking back and forth through it from
+    /// `S`, which also passes an `a`, up to `E`. Maps can be any size, so they can be wider or taller
+    /// than 127.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let width = size.max(6);
+        let height = (size * 2 / 3).max(5);
+        let mut rows = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
+        let path_len = rng.gen_range(27..width * height);
+        for i in 0..path_len {
+            let (x, y) = (i % width, i / width);
+            let x = if y % 2 == 0 { x } else { width - 1 - x };
+            rows[y][x] = (b'a' + (i * 25 / (path_len - 1)) as u8) as char;
+            if i == 0 {
+                rows[y][x] = START_CHAR;
+            } else if i == path_len - 1 {
+                rows[y][x] = END_CHAR;
+            }
+        }
+        if rng.gen() {
+            rows.reverse();
+        }
+        if rng.gen() {
+            rows.iter_mut().for_each(|row| row.reverse());
+        }
+        join_lines(rows.into_iter().map(|row| row.into_iter().collect()))
+    }
+}
+
+fn calculate_min_steps_to_end(
+    height_map: &Grid<u8>,
+    starting_frontier: HashSet<MapPos>,
+    end: MapPos,
+) -> u32 {
+    let search = bfs(
+        starting_frontier,
+        |&pos| {
+            let cur_height = height_map[pos];
+            height_map
+                .neighbors(pos, &NEIGHBORS_4)
+                .filter(move |&(_, &dest_height)| dest_height <= cur_height + 1)
+                .map(|(new_pos, _)| new_pos)
+        },
+        |&pos| pos == end,
+    );
+    let steps = search
+        .goal_distance()
+        .unwrap_or_else(|| panic!("No path found to {:?}", end));
+    return steps as u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day12>(EXAMPLE, Part::One), Ok(Answer::Int(31)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day12>(EXAMPLE, Part::Two), Ok(Answer::Int(29)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day12>(INPUT, Part::One), Ok(Answer::Int(350)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day12>(INPUT, Part::Two), Ok(Answer::Int(349)));
+    }
+
+    /// Relaxes every tile's distance from its neighbours until nothing changes, rather than searching.
+    fn brute_force_steps(input: &str, is_start: impl Fn(char) -> bool) -> u32 {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<_>>();
+        let height_of = |c: char| match c {
+            START_CHAR => b'a',
+            END_CHAR => b'z',
+            _ => c as u8,
+        };
+        let mut distances = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&c| if is_start(c) { Some(0) } else { None })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for y in 0..rows.len() {
+                for x in 0..rows[y].len() {
+                    let neighbours = [
+                        (x.wrapping_sub(1), y),
+                        (x + 1, y),
+                        (x, y.wrapping_sub(1)),
+                        (x, y + 1),
+                    ];
+                    for (nx, ny) in neighbours {
+                        let Some(Some(from)) =
+                            distances.get(ny).and_then(|row| row.get(nx)).copied()
+                        else {
+                            continue;
+                        };
+                        let climbable = height_of(rows[y][x]) <= height_of(rows[ny][nx]) + 1;
+                        if climbable && distances[y][x].is_none_or(|distance| distance > from + 1) {
+                            distances[y][x] = Some(from + 1);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        let (end_y, end_x) = rows
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.iter().position(|&c| c == END_CHAR).map(|x| (y, x)))
+            .unwrap();
+        distances[end_y][end_x].unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..40usize) {
+            let input = generate::<Day12>(seed, size);
+            let part1 = brute_force_steps(&input, |c| c == START_CHAR);
+            let part2 = brute_force_steps(&input, |c| c == START_CHAR || c == 'a');
+            prop_assert_eq!(solve::<Day12>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day12>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
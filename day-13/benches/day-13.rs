@@ -0,0 +1 @@
+common::bench_day!("day-13", day_13::Day13);
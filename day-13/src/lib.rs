@@ -0,0 +1,395 @@
+use common::{
+    answer::Answer,
+    generate::{Generator, Rng, StdRng},
+    input::{blocks, lines},
+    parse::{parse_num, ParseError},
+    solution::Solution,
+};
+use std::{collections::VecDeque, fmt};
+
+#[derive(PartialEq, Debug)]
+pub enum PacketItem {
+    Val(u8),
+    List(Vec<PacketItem>),
+}
+
+#[derive(PartialEq)]
+enum PacketOrderCorrectness {
+    Correct,
+    Incorrect,
+    Unknown,
+}
+
+/// How deeply lists may nest in a packet, so that deeply nested input fails to parse rather than
+/// overflowing the stack.
+const MAX_NESTING: i16 = 128;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<(PacketItem, PacketItem)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        blocks(input)
+            .map(|pair| {
+                let mut packets = lines(pair)
+                    .map(|line| parse_packet_item(input, line.trim()))
+                    .collect::<Result<VecDeque<PacketItem>, ParseError>>()?;
+                if packets.len() > 2 {
+                    let extra = lines(pair).nth(2).unwrap();
+                    return Err(ParseError::new(input, extra, "expected only two packets"));
+                }
+                let packet_1 = packets
+                    .pop_front()
+                    .ok_or_else(|| ParseError::missing(input, pair, "a packet"))?;
+                let packet_2 = packets
+                    .pop_front()
+                    .ok_or_else(|| ParseError::missing(input, pair, "a second packet"))?;
+                Ok((packet_1, packet_2))
+            })
+            .collect()
+    }
+
+    fn part1(packet_pairs: &Self::Input<'_>) -> Answer {
+        packet_pairs
+            .iter()
+            .enumerate()
+            .map(|(i, pair)| {
+                let (packet_1, packet_2) = pair;
+                let comparison = check_packet_items_correctly_ordered(packet_1, packet_2);
+                match comparison {
+                    PacketOrderCorrectness::Correct => i + 1,
+                    PacketOrderCorrectness::Incorrect => 0,
+                    PacketOrderCorrectness::Unknown => {
+                        panic!("Unknown top-level packet order: {:?}", pair)
+                    }
+                }
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(packet_pairs: &Self::Input<'_>) -> Answer {
+        let mut all_packets = packet_pairs
+            .iter()
+            .flat_map(|pair| {
+                let (packet_1, packet_2) = pair;
+                vec![packet_1, packet_2]
+            })
+            .collect::<Vec<&PacketItem>>();
+        let divider_packets = [
+            PacketItem::List(vec![PacketItem::List(vec![PacketItem::List(vec![
+                PacketItem::Val(2),
+            ])])]),
+            PacketItem::List(vec![PacketItem::List(vec![PacketItem::List(vec![
+                PacketItem::Val(6),
+            ])])]),
+        ];
+        all_packets.extend(divider_packets.iter());
+        all_packets.sort_by(|left, right| {
+            let comparison = check_packet_items_correctly_ordered(left, right);
+            match comparison {
+                PacketOrderCorrectness::Correct => std::cmp::Ordering::Less,
+                PacketOrderCorrectness::Incorrect => std::cmp::Ordering::Greater,
+                PacketOrderCorrectness::Unknown => std::cmp::Ordering::Equal,
+            }
+        });
+        let div_1_pos = all_packets
+            .iter()
+            .position(|&packet| *packet == divider_packets[0])
+            .unwrap();
+        let div_2_pos = all_packets
+            .iter()
+            .position(|&packet| *packet == divider_packets[1])
+            .unwrap();
+        ((div_1_pos + 1) * (div_2_pos + 1)).into()
+    }
+}
+
+impl Generator for Day13 {
+    /// Makes pairs of random packets, never two that compare as equal, so that every pair has an order
+    /// and the divider packets have a place of their own.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let dividers =
+            [2, 6].map(|val| PacketItem::List(vec![PacketItem::List(vec![PacketItem::Val(val)])]));
+        let random_packet = |rng: &mut StdRng| loop {
+            let packet = PacketItem::List(random_packet_items(rng, 0));
+            if dividers.iter().all(|divider| {
+                check_packet_items_correctly_ordered(&packet, divider)
+                    != PacketOrderCorrectness::Unknown
+            }) {
+                return packet;
+            }
+        };
+        let pairs = (0..size.max(1)).map(|_| {
+            let left = random_packet(rng);
+            let right = loop {
+                let right = random_packet(rng);
+                if check_packet_items_correctly_ordered(&left, &right)
+                    != PacketOrderCorrectness::Unknown
+                {
+                    break right;
+                }
+            };
+            format!("{}\n{}", left, right)
+        });
+        pairs.collect::<Vec<String>>().join("\n\n")
+    }
+}
+
+fn random_packet_items(rng: &mut StdRng, depth: usize) -> Vec<PacketItem> {
+    (0..rng.gen_range(0..=4))
+        .map(|_| {
+            if depth < 4 && rng.gen_ratio(1, 3) {
+                PacketItem::List(random_packet_items(rng, depth + 1))
+            } else {
+                PacketItem::Val(rng.gen_range(0..=10))
+            }
+        })
+        .collect()
+}
+
+impl fmt::Display for PacketItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PacketItem::Val(val) => write!(f, "{}", val),
+            PacketItem::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+fn parse_packet_item(input: &str, packet_data: &str) -> Result<PacketItem, ParseError> {
+    let mut packet_items = Vec::new();
+    let mut buffer_start = 0;
+    let mut cur_depth = 0_i16;
+    for (i, c) in packet_data.char_indices() {
+        match c {
+            '[' => {
+                if cur_depth == 0 {
+                    buffer_start = i;
+                }
+                cur_depth += 1;
+                if cur_depth > MAX_NESTING {
+                    return Err(ParseError::new(
+                        input,
+                        &packet_data[i..i + 1],
+                        format!("expected lists nested at most {} deep", MAX_NESTING),
+                    ));
+                }
+            }
+            ']' => {
+                cur_depth -= 1;
+                if cur_depth < 0 {
+                    return Err(ParseError::new(
+                        input,
+                        &packet_data[i..i + 1],
+                        "unmatched closing bracket",
+                    ));
+                } else if cur_depth == 0 {
+                    let nested_packet_item_data = &packet_data[buffer_start + 1..i];
+                    packet_items.push(parse_packet_item(input, nested_packet_item_data)?);
+                    buffer_start = i + 1;
+                }
+            }
+            ',' => {
+                if cur_depth == 0 {
+                    if let Some(item) = parse_buffer_to_item(input, &packet_data[buffer_start..i])?
+                    {
+                        packet_items.push(item);
+                    }
+                    buffer_start = i + 1;
+                }
+            }
+            '0'..='9' => {}
+            _ => {
+                return Err(ParseError::new(
+                    input,
+                    &packet_data[i..i + c.len_utf8()],
+                    "expected a bracket, comma or digit",
+                ));
+            }
+        }
+    }
+    if cur_depth != 0 {
+        return Err(ParseError::new(
+            input,
+            &packet_data[buffer_start..buffer_start + 1],
+            "unmatched opening bracket",
+        ));
+    }
+    if let Some(item) = parse_buffer_to_item(input, &packet_data[buffer_start..])? {
+        packet_items.push(item);
+    }
+    return Ok(PacketItem::List(packet_items));
+}
+
+fn parse_buffer_to_item(input: &str, buffer: &str) -> Result<Option<PacketItem>, ParseError> {
+    if !buffer.is_empty() {
+        return Ok(Some(PacketItem::Val(parse_num(input, buffer)?)));
+    } else {
+        return Ok(None);
+    }
+}
+
+fn check_packet_items_correctly_ordered(
+    left_item: &PacketItem,
+    right_item: &PacketItem,
+) -> PacketOrderCorrectness {
+    match (left_item, right_item) {
+        (PacketItem::Val(left_val), PacketItem::Val(right_val)) => {
+            if left_val < right_val {
+                return PacketOrderCorrectness::Correct;
+            } else if left_val > right_val {
+                return PacketOrderCorrectness::Incorrect;
+            }
+            return PacketOrderCorrectness::Unknown;
+        }
+        (PacketItem::List(left_list), PacketItem::List(right_list)) => {
+            for i in 0..left_list.len() {
+                if i >= right_list.len() {
+                    return PacketOrderCorrectness::Incorrect;
+                }
+                let sub_item_ordered =
+                    check_packet_items_correctly_ordered(&left_list[i], &right_list[i]);
+                match sub_item_ordered {
+                    PacketOrderCorrectness::Correct => return PacketOrderCorrectness::Correct,
+                    PacketOrderCorrectness::Incorrect => return PacketOrderCorrectness::Incorrect,
+                    PacketOrderCorrectness::Unknown => continue,
+                }
+            }
+            if left_list.len() < right_list.len() {
+                return PacketOrderCorrectness::Correct;
+            }
+            return PacketOrderCorrectness::Unknown;
+        }
+        (PacketItem::Val(left_val), PacketItem::List(_)) => {
+            let nested_item = PacketItem::List(vec![PacketItem::Val(*left_val)]);
+            let ordered = check_packet_items_correctly_ordered(&nested_item, right_item);
+            return ordered;
+        }
+        (PacketItem::List(_), PacketItem::Val(right_val)) => {
+            let nested_item = PacketItem::List(vec![PacketItem::Val(*right_val)]);
+            let ordered = check_packet_items_correctly_ordered(left_item, &nested_item);
+            return ordered;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day13>(EXAMPLE, Part::One), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day13>(EXAMPLE, Part::Two), Ok(Answer::Int(140)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day13>(INPUT, Part::One), Ok(Answer::Int(5555)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day13>(INPUT, Part::Two), Ok(Answer::Int(22852)));
+    }
+
+    #[test]
+    fn deeply_nested_packets_are_errors() {
+        let packet = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+        let input = format!("{}\n[]", packet);
+        assert!(Day13::parse(&input).is_err());
+    }
+
+    /// A packet parsed independently of the solver, ordered by the puzzle's rules through `Ord`.
+    #[derive(PartialEq, Eq)]
+    enum Packet {
+        Int(u32),
+        List(Vec<Packet>),
+    }
+
+    impl Packet {
+        fn parse(chars: &mut std::iter::Peekable<std::str::Chars>) -> Packet {
+            if chars.peek() != Some(&'[') {
+                let mut digits = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+                return Packet::Int(digits.parse().unwrap());
+            }
+            chars.next();
+            let mut items = Vec::new();
+            while chars.peek() != Some(&']') {
+                items.push(Packet::parse(chars));
+                chars.next_if_eq(&',');
+            }
+            chars.next();
+            Packet::List(items)
+        }
+    }
+
+    impl Ord for Packet {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            match (self, other) {
+                (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+                (Packet::List(a), Packet::List(b)) => a.iter().cmp(b.iter()),
+                (Packet::Int(a), list) => Packet::List(vec![Packet::Int(*a)]).cmp(list),
+                (list, Packet::Int(b)) => list.cmp(&Packet::List(vec![Packet::Int(*b)])),
+            }
+        }
+    }
+
+    impl PartialOrd for Packet {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    fn brute_force_ordering(input: &str) -> (usize, usize) {
+        let packets = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| Packet::parse(&mut line.chars().peekable()))
+            .collect::<Vec<Packet>>();
+        let part1 = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1)
+            .sum();
+        let divider = |line: &str| Packet::parse(&mut line.chars().peekable());
+        let before = |divider: &Packet| packets.iter().filter(|packet| *packet < divider).count();
+        let part2 = (1 + before(&divider("[[2]]"))) * (2 + before(&divider("[[6]]")));
+        (part1, part2)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day13>(seed, size);
+            let (part1, part2) = brute_force_ordering(&input);
+            prop_assert_eq!(solve::<Day13>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day13>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
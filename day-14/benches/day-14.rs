@@ -0,0 +1 @@
+common::bench_day!("day-14", day_14::Day14);
@@ -0,0 +1,251 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::lines,
+    parse::{parse_num_pair, ParseError},
+    pos::{MapPos, MapPosComp},
+    solution::{ConfiguredSolution, Part, Solution},
+    visualize::{Frame, Observer},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const SAND_ORIGIN: MapPos = (500, 0);
+const SAND_MOVEMENT_DIRECTIONS: [(i8, i8); 3] = [(0, 1), (-1, 1), (1, 1)];
+
+type SegmentedLine = Vec<MapPos>;
+
+pub struct Day14;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day14Config {
+    /// Where the sand pours in from.
+    pub sand_origin: MapPos,
+}
+
+impl Default for Day14Config {
+    fn default() -> Self {
+        Day14Config {
+            sand_origin: SAND_ORIGIN,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input<'a> = HashSet<MapPos>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines = lines(input).map(|line| line.trim()).collect::<Vec<&str>>();
+        let segments = lines
+            .iter()
+            .map(|line| {
+                let segment = line
+                    .split("->")
+                    .map(|vertex| parse_num_pair(input, vertex.trim(), ","))
+                    .collect::<Result<SegmentedLine, ParseError>>()?;
+                let is_straight =
+                    |pair: &[MapPos]| pair[0].0 == pair[1].0 || pair[0].1 == pair[1].1;
+                if !segment.windows(2).all(is_straight) {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        "expected only horizontal and vertical walls",
+                    ));
+                }
+                Ok(segment)
+            })
+            .collect::<Result<Vec<SegmentedLine>, ParseError>>()?;
+
+        let mut walls = HashSet::new();
+        for segment in segments {
+            for window in segment.windows(2) {
+                let last_vertex = window[0];
+                let current_vertex = window[1];
+                let x_range = get_range(last_vertex.0, current_vertex.0);
+                let y_range = get_range(last_vertex.1, current_vertex.1);
+                for x in x_range {
+                    walls.insert((x, last_vertex.1));
+                }
+                for y in y_range {
+                    walls.insert((last_vertex.0, y));
+                }
+            }
+        }
+        Ok(walls)
+    }
+
+    fn part1(walls: &Self::Input<'_>) -> Answer {
+        Self::watch(walls, Part::One, &mut ())
+    }
+
+    fn part2(walls: &Self::Input<'_>) -> Answer {
+        Self::watch(walls, Part::Two, &mut ())
+    }
+
+    fn watch(walls: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        Self::watch_with(walls, part, &Day14Config::default(), observer)
+    }
+}
+
+impl ConfiguredSolution for Day14 {
+    type Config = Day14Config;
+
+    fn part1_with(walls: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        Self::watch_with(walls, Part::One, config, &mut ())
+    }
+
+    fn part2_with(walls: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        Self::watch_with(walls, Part::Two, config, &mut ())
+    }
+
+    fn watch_with(
+        walls: &Self::Input<'_>,
+        part: Part,
+        config: &Self::Config,
+        observer: &mut dyn Observer,
+    ) -> Answer {
+        let (settled_before_bottom_reached, settled_total) =
+            pour_sand(walls, config.sand_origin, observer);
+        match part {
+            Part::One => settled_before_bottom_reached
+                .expect("Sand never fell past the lowest wall")
+                .into(),
+            Part::Two => settled_total.into(),
+        }
+    }
+}
+
+impl Generator for Day14 {
+    /// Makes rock paths of straight lines below the source of the sand, trying again whenever they
+    /// would catch all the sand before any of it fell past the lowest rock.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let depth = 10 + size as MapPosComp / 2;
+        loop {
+            let paths = (0..size.max(1))
+                .map(|_| {
+                    let mut vertex = (
+                        SAND_ORIGIN.0 + rng.gen_range(-depth..=depth),
+                        rng.gen_range(2..=depth),
+                    );
+                    let mut path = vec![vertex];
+                    for i in 0..rng.gen_range(1..=4) {
+                        let step = rng.gen_range(1..=8) * if rng.gen() { 1 } else { -1 };
+                        if i % 2 == 0 {
+                            vertex.0 += step;
+                        } else {
+                            vertex.1 = (vertex.1 + step).max(2);
+                        }
+                        path.push(vertex);
+                    }
+                    path.iter()
+                        .map(|(x, y)| format!("{},{}", x, y))
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                })
+                .collect::<Vec<String>>();
+            let input = join_lines(paths);
+            let walls = Day14::parse(&input).unwrap();
+            if pour_sand(&walls, SAND_ORIGIN, &mut ()).0.is_some() {
+                return input;
+            }
+        }
+    }
+}
+
+/// Pours sand from `origin` until it's blocked, returning how much had settled when sand first fell
+/// past the lowest wall, if it ever did, and how much settled in total. The observer sees each grain
+/// come to rest.
+fn pour_sand(
+    walls: &HashSet<MapPos>,
+    origin: MapPos,
+    observer: &mut dyn Observer,
+) -> (Option<usize>, usize) {
+    let lowest_wall_y = walls.iter().max_by(|a, b| a.1.cmp(&b.1)).unwrap().1;
+    let floor_y = lowest_wall_y + 2;
+
+    let mut settled_sand = HashSet::new();
+    let mut bottom_reached_at = None;
+    let mut end_reached = false;
+    while !end_reached {
+        let mut moving_sand = origin;
+        loop {
+            let mut moved = false;
+            for dir in SAND_MOVEMENT_DIRECTIONS {
+                let dest = (
+                    moving_sand.0 + dir.0 as MapPosComp,
+                    moving_sand.1 + dir.1 as MapPosComp,
+                );
+                if moving_sand.1 < floor_y - 1
+                    && !walls.contains(&dest)
+                    && !settled_sand.contains(&dest)
+                {
+                    moving_sand = dest;
+                    moved = true;
+                    break;
+                }
+            }
+            if !moved {
+                settled_sand.insert(moving_sand);
+                observer.step(&|| draw_cave(walls, &settled_sand, origin));
+                break;
+            }
+            if bottom_reached_at.is_none() && moving_sand.1 > lowest_wall_y {
+                bottom_reached_at = Some(settled_sand.len());
+            }
+        }
+        if settled_sand.contains(&origin) {
+            end_reached = true;
+        }
+    }
+    (bottom_reached_at, settled_sand.len())
+}
+
+/// Draws the cave's walls as `#`, settled sand as `o` and the source of the sand as `+`.
+fn draw_cave(walls: &HashSet<MapPos>, settled_sand: &HashSet<MapPos>, origin: MapPos) -> Frame {
+    let walls = walls.iter().map(|&pos| (pos, '#'));
+    let sand = settled_sand.iter().map(|&pos| (pos, 'o'));
+    let source = [(origin, '+')];
+    Frame::from_points(walls.chain(sand).chain(source), '.')
+}
+
+fn get_range(p1: MapPosComp, p2: MapPosComp) -> Vec<MapPosComp> {
+    if p1 < p2 {
+        return (p1..=p2).collect();
+    }
+    return (p2..=p1).rev().collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day14>(EXAMPLE, Part::One), Ok(Answer::Int(24)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day14>(EXAMPLE, Part::Two), Ok(Answer::Int(93)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day14>(INPUT, Part::One), Ok(Answer::Int(1001)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day14>(INPUT, Part::Two), Ok(Answer::Int(27976)));
+    }
+
+    #[test]
+    fn diagonal_walls_are_errors() {
+        assert!(Day14::parse("498,4 -> 498,6 -> 496,8").is_err());
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-15", day_15::Day15);
@@ -0,0 +1,252 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::{parse_num, parse_num_pair, split_pair, strip_literal, ParseError},
+    solution::{ConfiguredSolution, Solution},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+type PosComp = i64;
+
+type Pos = (PosComp, PosComp);
+
+type SensorBeaconPair = (Pos, Pos);
+
+pub struct Day15;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day15Config {
+    /// The row that part 1 counts the positions without a beacon in.
+    pub target_row: PosComp,
+    /// Part 2's distress beacon has both coordinates between 0 and this.
+    pub max_coordinate: PosComp,
+}
+
+impl Default for Day15Config {
+    fn default() -> Self {
+        Day15Config {
+            target_row: 2000000,
+            max_coordinate: 4000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<SensorBeaconPair>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let line = line.trim();
+                if line.starts_with("Sensor") {
+                    return parse_raw_pair(input, line);
+                }
+                let (sensor, beacon) = split_pair(input, line, ":")?;
+                Ok((
+                    parse_num_pair(input, sensor.trim(), ",")?,
+                    parse_num_pair(input, beacon.trim(), ",")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(sensor_beacon_pairs: &Self::Input<'_>) -> Answer {
+        Self::part1_with(sensor_beacon_pairs, &Day15Config::default())
+    }
+
+    fn part2(sensor_beacon_pairs: &Self::Input<'_>) -> Answer {
+        Self::part2_with(sensor_beacon_pairs, &Day15Config::default())
+    }
+}
+
+impl ConfiguredSolution for Day15 {
+    type Config = Day15Config;
+
+    fn example_config() -> Self::Config {
+        Day15Config {
+            target_row: 10,
+            max_coordinate: 20,
+        }
+    }
+
+    fn part1_with(sensor_beacon_pairs: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let ranges = get_beacon_exclusion_ranges(sensor_beacon_pairs, config.target_row);
+        ranges
+            .iter()
+            .map(|range| range.1 - range.0)
+            .sum::<PosComp>()
+            .into()
+    }
+
+    fn part2_with(sensor_beacon_pairs: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let min_dim = 0;
+        let max_dim = config.max_coordinate;
+        for y in min_dim..=max_dim {
+            let ranges = get_beacon_exclusion_ranges(sensor_beacon_pairs, y);
+            if let Some(x) = find_uncovered_x(&ranges, min_dim, max_dim) {
+                let tuning_freq = x * 4000000 + y;
+                return tuning_freq.into();
+            }
+        }
+        panic!("No possible beacon position found");
+    }
+}
+
+/// Parses a line as the puzzle gives it, like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+fn parse_raw_pair(input: &str, line: &str) -> Result<SensorBeaconPair, ParseError> {
+    let rest = strip_literal(input, line, "Sensor at ")?;
+    let (sensor, beacon) = split_pair(input, rest, ": closest beacon is at ")?;
+    let parse_pos = |pos: &str| -> Result<Pos, ParseError> {
+        let (x, y) = split_pair(input, pos, ", ")?;
+        Ok((
+            parse_num(input, strip_literal(input, x, "x=")?)?,
+            parse_num(input, strip_literal(input, y, "y=")?)?,
+        ))
+    };
+    Ok((parse_pos(sensor)?, parse_pos(beacon)?))
+}
+
+impl Generator for Day15 {
+    /// Hides the distress beacon at a random point, then surrounds it with four sensors diagonally
+    /// outside the search area whose ranges reach every other point in it. The rest of the sensors
+    /// sit near those four sensors' beacons, sharing them.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let hidden = (rng.gen_range(0..=4000000), rng.gen_range(0..=4000000));
+        let reach = rng.gen_range(4000000..=5000000);
+        let mut pairs = Vec::new();
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let sensor = (hidden.0 + dx * reach, hidden.1 + dy * reach);
+            let beacon = (sensor.0, sensor.1 + dy * (2 * reach - 1));
+            pairs.push((sensor, beacon));
+        }
+        let beacons = pairs.iter().map(|pair| pair.1).collect::<Vec<Pos>>();
+        while pairs.len() < size.max(4) {
+            let beacon = *beacons.choose(rng).unwrap();
+            let sensor = (
+                beacon.0 + rng.gen_range(-reach / 4..=reach / 4),
+                beacon.1 + rng.gen_range(-reach / 4..=reach / 4),
+            );
+            if pairs.iter().all(|&(s, b)| s != sensor && b != sensor) {
+                pairs.push((sensor, beacon));
+            }
+        }
+        pairs.shuffle(rng);
+        join_lines(
+            pairs
+                .iter()
+                .map(|(s, b)| format!("{},{}:{},{}", s.0, s.1, b.0, b.1)),
+        )
+    }
+}
+
+/// Finds the leftmost x between `min_dim` and `max_dim` that none of the ranges cover.
+fn find_uncovered_x(ranges: &HashSet<Pos>, min_dim: PosComp, max_dim: PosComp) -> Option<PosComp> {
+    let mut ranges = ranges.iter().copied().collect::<Vec<Pos>>();
+    ranges.sort();
+    let mut x = min_dim;
+    for (start, end) in ranges {
+        if start > x {
+            break;
+        }
+        x = x.max(end + 1);
+    }
+    if x <= max_dim {
+        return Some(x);
+    }
+    return None;
+}
+
+fn get_beacon_exclusion_ranges(
+    sensor_beacon_pairs: &Vec<SensorBeaconPair>,
+    target_row: PosComp,
+) -> HashSet<Pos> {
+    let mut row_ranges = vec![];
+    for pair in sensor_beacon_pairs {
+        let manhattan_distance = ((pair.0).0 - (pair.1).0).abs() + ((pair.0).1 - (pair.1).1).abs();
+        let distance_to_row = (target_row - (pair.0).1).abs();
+        if manhattan_distance >= distance_to_row {
+            let lateral_distance = manhattan_distance - distance_to_row;
+            let row_range = (pair.0 .0 - lateral_distance, pair.0 .0 + lateral_distance);
+            row_ranges.push(row_range);
+        }
+    }
+    let mut master_ranges = HashSet::new();
+    for row_range in row_ranges {
+        master_ranges = insert_into_bounds_set(master_ranges, row_range);
+    }
+    return master_ranges;
+}
+
+fn insert_into_bounds_set(bounds: HashSet<Pos>, item: Pos) -> HashSet<Pos> {
+    let mut new_bounds = HashSet::new();
+    if bounds.is_empty() {
+        new_bounds.insert(item);
+        return new_bounds;
+    }
+    let mut cur_bound = item;
+    for bound in bounds.iter() {
+        if bound.0 <= cur_bound.0 && cur_bound.1 <= bound.1 {
+            return bounds;
+        } else if cur_bound.0 <= bound.0 && bound.1 <= cur_bound.1 {
+            continue;
+        } else if bound.0 <= cur_bound.0 && cur_bound.0 <= bound.1 {
+            let new_bound = (bound.0, cur_bound.1);
+            cur_bound = new_bound;
+        } else if bound.0 <= cur_bound.1 && cur_bound.1 <= bound.1 {
+            let new_bound = (cur_bound.0, bound.1);
+            cur_bound = new_bound;
+        } else {
+            new_bounds.insert(*bound);
+        }
+    }
+    new_bounds.insert(cur_bound);
+    return new_bounds;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, solve_with, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const RAW_EXAMPLE: &str = include_str!("../resources/example-raw.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_with::<Day15>(EXAMPLE, Part::One, &Day15::example_config()),
+            Ok(Answer::Int(26))
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_with::<Day15>(EXAMPLE, Part::Two, &Day15::example_config()),
+            Ok(Answer::Int(56000011))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day15>(INPUT, Part::One), Ok(Answer::Int(4665948)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day15>(INPUT, Part::Two),
+            Ok(Answer::Int(13543690671045))
+        );
+    }
+
+    #[test]
+    fn raw_puzzle_format_matches_compact_format() {
+        assert_eq!(Day15::parse(RAW_EXAMPLE), Day15::parse(EXAMPLE));
+        assert!(Day15::parse("Sensor at x=2, y=18: closest beacon at x=-2, y=15").is_err());
+    }
+}
@@ -0,0 +1,2 @@
+// Part 2 runs out of memory on the real input, so only part 1 is timed.
+common::bench_day!("day-16", day_16::Day16, [common::solution::Part::One]);
@@ -0,0 +1,546 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::{parse_num, split_pair, strip_literal, ParseError},
+    search::{bfs, SearchStats},
+    solution::{ConfiguredSolution, Solution},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use tracing::info;
+
+type ValveIdentifier = u8;
+
+type StepAmount = u16;
+
+type FlowAmount = u16;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct ValveState {
+    player_transits: (Option<PlayerTransit>, Option<PlayerTransit>),
+    open_valves: Vec<bool>,
+    steps_left: StepAmount,
+    pressure_released: FlowAmount,
+}
+
+type PlayerTransit = (ValveIdentifier, StepAmount);
+
+impl ValveState {
+    fn new(network: &ValveNetwork, steps_left: StepAmount, is_two_player: bool) -> ValveState {
+        ValveState {
+            player_transits: (
+                Some((0, 0)),
+                if is_two_player { Some((0, 0)) } else { None },
+            ),
+            open_valves: vec![false; network.len()],
+            steps_left,
+            pressure_released: 0,
+        }
+    }
+
+    fn get_max_possible_released_pressure(&self, network: &ValveNetwork) -> FlowAmount {
+        let releases = self.get_possible_final_pressure_releases(network);
+        return *releases.iter().max().unwrap();
+    }
+
+    fn get_possible_final_pressure_releases(&self, network: &ValveNetwork) -> Vec<FlowAmount> {
+        let players = if self.player_transits.1.is_some() {
+            2
+        } else {
+            1
+        };
+        let _span =
+            tracing::info_span!("valve_search", steps_left = self.steps_left, players).entered();
+        let mut stats = SearchStats::new();
+        let search = bfs(
+            [self.clone()],
+            |state| state.get_successors(network, &mut stats),
+            |_| false,
+        );
+        let releases = search
+            .distances()
+            .keys()
+            .filter(|state| state.is_done())
+            .map(|state| state.pressure_released)
+            .collect::<Vec<FlowAmount>>();
+        info!(
+            final_states = releases.len(),
+            pruned = %stats.pruned_summary(),
+            "valve moves pruned"
+        );
+        releases
+    }
+
+    fn get_successors(&self, network: &ValveNetwork, stats: &mut SearchStats) -> Vec<ValveState> {
+        let mut successors = Vec::new();
+        if self.is_done() {
+            return successors;
+        }
+
+        let mut player_positions = (None, None);
+        let mut updated_player_transits = self.player_transits;
+        let mut updated_open_valves = self.open_valves.clone();
+        if let Some(player_1_transit) = self.player_transits.0 {
+            if player_1_transit.1 == 0 {
+                player_positions.0 = Some(player_1_transit.0);
+                updated_player_transits.0 = None;
+                updated_open_valves[player_1_transit.0 as usize] = true;
+            }
+        }
+        if let Some(player_2_transit) = self.player_transits.1 {
+            if player_2_transit.1 == 0 {
+                player_positions.1 = Some(player_2_transit.0);
+                updated_player_transits.1 = None;
+                updated_open_valves[player_2_transit.0 as usize] = true;
+            }
+        }
+
+        let player_1_paths = if let Some(player_1_position) = player_positions.0 {
+            self.get_shortest_paths_to_useful_valves(network, player_1_position, stats)
+        } else {
+            HashMap::new()
+        };
+        let player_2_paths = if let Some(player_2_position) = player_positions.1 {
+            self.get_shortest_paths_to_useful_valves(network, player_2_position, stats)
+        } else {
+            HashMap::new()
+        };
+
+        if player_1_paths.is_empty() && player_2_paths.is_empty() {
+            let successor = ValveState {
+                player_transits: updated_player_transits,
+                open_valves: updated_open_valves.clone(),
+                steps_left: 0,
+                pressure_released: self.pressure_released
+                    + self.get_step_pressure_released(&updated_open_valves, network)
+                        * self.steps_left,
+            };
+            successors.push(successor);
+            return successors;
+        }
+
+        let mut destination_combinations = vec![];
+        if player_1_paths.is_empty() {
+            destination_combinations = player_2_paths
+                .iter()
+                .map(|(valve, distance)| ((None, None), (Some(*valve), Some(*distance))))
+                .collect();
+        } else if player_2_paths.is_empty() {
+            destination_combinations = player_1_paths
+                .iter()
+                .map(|(valve, distance)| ((Some(*valve), Some(*distance)), (None, None)))
+                .collect();
+        } else {
+            for (p1_valve, p1_distance) in &player_1_paths {
+                for (p2_valve, p2_distance) in &player_2_paths {
+                    destination_combinations.push((
+                        (Some(*p1_valve), Some(*p1_distance)),
+                        (Some(*p2_valve), Some(*p2_distance)),
+                    ));
+                }
+            }
+        }
+
+        for combination in destination_combinations {
+            let mut new_player_transits = updated_player_transits;
+            if let Some(player_1_destination) = combination.0 .0 {
+                new_player_transits.0 = Some((player_1_destination, combination.0 .1.unwrap() + 1));
+            }
+            if let Some(player_2_destination) = combination.1 .0 {
+                new_player_transits.1 = Some((player_2_destination, combination.1 .1.unwrap() + 1));
+            }
+            let steps_until_next_arrival = match new_player_transits.0 {
+                Some(transit) => transit.1.min(new_player_transits.1.unwrap_or(transit).1),
+                None => new_player_transits.1.unwrap().1,
+            };
+            if let Some(transit) = new_player_transits.0 {
+                new_player_transits.0 = Some((transit.0, transit.1 - steps_until_next_arrival));
+            }
+            if let Some(transit) = new_player_transits.1 {
+                new_player_transits.1 = Some((transit.0, transit.1 - steps_until_next_arrival));
+            }
+            let successor = ValveState {
+                player_transits: new_player_transits,
+                open_valves: updated_open_valves.clone(),
+                steps_left: self.steps_left - steps_until_next_arrival,
+                pressure_released: self.pressure_released
+                    + self.get_step_pressure_released(&updated_open_valves, network)
+                        * steps_until_next_arrival,
+            };
+            successors.push(successor);
+        }
+        return successors;
+    }
+
+    fn is_done(&self) -> bool {
+        self.steps_left == 0
+    }
+
+    fn valve_is_open(&self, valve_id: ValveIdentifier) -> bool {
+        self.open_valves[valve_id as usize]
+    }
+
+    fn get_step_pressure_released(
+        &self,
+        open_valves: &[bool],
+        network: &ValveNetwork,
+    ) -> FlowAmount {
+        let mut sum = 0;
+        for (valve_id, valve) in network.iter() {
+            if open_valves[*valve_id as usize] {
+                sum += valve.flow;
+            }
+        }
+        return sum;
+    }
+
+    fn get_shortest_paths_to_useful_valves(
+        &self,
+        network: &ValveNetwork,
+        position: ValveIdentifier,
+        stats: &mut SearchStats,
+    ) -> HashMap<ValveIdentifier, StepAmount> {
+        let current_valve = network.get(&position).unwrap();
+        current_valve
+            .neighbors
+            .iter()
+            .filter(|&(&valve, &distance)| {
+                if distance == 0 {
+                    return false;
+                }
+                let reason = if network.get_valve(valve).flow == 0 {
+                    "valves without flow"
+                } else if self.valve_is_open(valve) {
+                    "valves already open"
+                } else if distance + 1 > self.steps_left {
+                    "valves too far to open in time"
+                } else {
+                    return true;
+                };
+                stats.prune(reason);
+                false
+            })
+            .map(|(valve, distance)| (*valve, *distance))
+            .collect()
+    }
+}
+
+type ValveNetwork = HashMap<ValveIdentifier, Valve>;
+
+trait HasValve {
+    fn get_valve(&self, valve_id: ValveIdentifier) -> &Valve;
+}
+
+impl HasValve for ValveNetwork {
+    fn get_valve(&self, valve_id: ValveIdentifier) -> &Valve {
+        self.get(&valve_id).unwrap()
+    }
+}
+
+fn compute_valve_network(named_valves: HashMap<String, (StepAmount, Vec<String>)>) -> ValveNetwork {
+    let mut network = HashMap::new();
+    let mut valve_ids = named_valves
+        .iter()
+        .collect::<Vec<(&String, &(StepAmount, Vec<String>))>>();
+    valve_ids.sort_by(|a, b| a.0.cmp(b.0));
+    let valve_ids = valve_ids
+        .iter()
+        .enumerate()
+        .map(move |(i, (name, _))| ((*name).clone(), i as ValveIdentifier))
+        .collect::<HashMap<String, ValveIdentifier>>();
+    for (current_valve_name, (current_valve_flow, _)) in &named_valves {
+        let current_valve_id = valve_ids.get(current_valve_name).unwrap();
+        let search = bfs(
+            [current_valve_name.as_str()],
+            |&valve_name| named_valves[valve_name].1.iter().map(|name| name.as_str()),
+            |_| false,
+        );
+        let paths = search
+            .distances()
+            .iter()
+            .map(|(&valve_name, &distance)| (valve_ids[valve_name], distance as StepAmount))
+            .collect();
+        let new_valve = Valve {
+            flow: *current_valve_flow,
+            neighbors: paths,
+        };
+        network.insert(*current_valve_id, new_valve);
+    }
+    return network;
+}
+
+pub struct Valve {
+    flow: FlowAmount,
+    neighbors: HashMap<ValveIdentifier, StepAmount>,
+}
+
+pub struct Day16;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day16Config {
+    /// How long part 1 has to release pressure alone.
+    pub minutes_alone: StepAmount,
+    /// How long part 2 has to release pressure with the elephant's help.
+    pub minutes_with_elephant: StepAmount,
+}
+
+impl Default for Day16Config {
+    fn default() -> Self {
+        Day16Config {
+            minutes_alone: 30,
+            minutes_with_elephant: 26,
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input<'a> = ValveNetwork;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let valves = lines(input)
+            .map(|line| {
+                let line = line.trim();
+                if line.starts_with("Valve") {
+                    return parse_raw_valve(input, line);
+                }
+                let (name, rest) = split_pair(input, line, ":")?;
+                let (flow, neighbor_names) = split_pair(input, rest, ":")?;
+                let flow = parse_num::<FlowAmount>(input, flow)?;
+                Ok((name, flow, neighbor_names.split(",").collect::<Vec<&str>>()))
+            })
+            .collect::<Result<Vec<(&str, FlowAmount, Vec<&str>)>, ParseError>>()?;
+        let names = valves
+            .iter()
+            .map(|(name, _, _)| *name)
+            .collect::<HashSet<&str>>();
+        for (_, _, neighbor_names) in &valves {
+            if let Some(unknown) = neighbor_names.iter().find(|name| !names.contains(*name)) {
+                return Err(ParseError::new(input, unknown, "no such valve"));
+            }
+        }
+        let named_valves = valves
+            .into_iter()
+            .map(|(name, flow, neighbor_names)| {
+                let neighbor_names = neighbor_names.iter().map(|s| s.to_string()).collect();
+                (name.to_owned(), (flow, neighbor_names))
+            })
+            .collect::<HashMap<String, (StepAmount, Vec<String>)>>();
+        Ok(compute_valve_network(named_valves))
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Answer {
+        Self::part1_with(network, &Day16Config::default())
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Answer {
+        Self::part2_with(network, &Day16Config::default())
+    }
+}
+
+impl ConfiguredSolution for Day16 {
+    type Config = Day16Config;
+
+    fn part1_with(network: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let initial_state = ValveState::new(network, config.minutes_alone, false);
+        let max_release = initial_state.get_max_possible_released_pressure(network);
+        max_release.into()
+    }
+
+    fn part2_with(network: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let initial_state = ValveState::new(network, config.minutes_with_elephant, true);
+        let max_release = initial_state.get_max_possible_released_pressure(network);
+        max_release.into()
+    }
+}
+
+/// Parses a line as the puzzle gives it, like `Valve AA has flow rate=0; tunnels lead to valves DD, BB`,
+/// where a valve with only one tunnel says `tunnel leads to valve` instead.
+fn parse_raw_valve<'a>(
+    input: &str,
+    line: &'a str,
+) -> Result<(&'a str, FlowAmount, Vec<&'a str>), ParseError> {
+    let rest = strip_literal(input, line, "Valve ")?;
+    let (name, rest) = split_pair(input, rest, " has flow rate=")?;
+    let (flow, tunnels) = split_pair(input, rest, "; ")?;
+    let flow = parse_num::<FlowAmount>(input, flow)?;
+    let neighbor_names = tunnels
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| {
+            ParseError::new(input, tunnels, "expected the valves the tunnels lead to")
+        })?;
+    Ok((
+        name,
+        flow,
+        neighbor_names.split(",").map(|name| name.trim()).collect(),
+    ))
+}
+
+impl Generator for Day16 {
+    /// Makes a connected network of tunnels starting at `AA`, which has no flow like in the real
+    /// input. At most seven valves have any flow, which keeps part 2 quick enough to solve.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let valve_count = size.clamp(2, 26 * 26);
+        let mut names = (b'A'..=b'Z')
+            .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+            .skip(1)
+            .collect::<Vec<String>>();
+        names.shuffle(rng);
+        names.insert(0, "AA".to_owned());
+        let mut tunnels = vec![BTreeSet::new(); valve_count];
+        for valve in 1..valve_count {
+            let other = rng.gen_range(0..valve);
+            tunnels[valve].insert(other);
+            tunnels[other].insert(valve);
+        }
+        for _ in 0..valve_count / 3 {
+            let (a, b) = (rng.gen_range(0..valve_count), rng.gen_range(0..valve_count));
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        }
+        let mut flows = vec![0; valve_count];
+        let mut useful = (1..valve_count).collect::<Vec<usize>>();
+        useful.shuffle(rng);
+        for &valve in useful.iter().take(7.min(size / 4 + 1)) {
+            flows[valve] = rng.gen_range(1..=25);
+        }
+        let mut valves = (0..valve_count)
+            .map(|valve| {
+                let neighbors = tunnels[valve]
+                    .iter()
+                    .map(|&other| names[other].as_str())
+                    .collect::<Vec<&str>>();
+                format!("{}:{}:{}", names[valve], flows[valve], neighbors.join(","))
+            })
+            .collect::<Vec<String>>();
+        valves.shuffle(rng);
+        join_lines(valves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const RAW_EXAMPLE: &str = include_str!("../resources/example-raw.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    // Part 2 keeps every state it has seen, and on the real input runs out of memory before
+    // finishing, so there's no known answer to check it against yet.
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day16>(EXAMPLE, Part::One), Ok(Answer::Int(1651)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day16>(EXAMPLE, Part::Two), Ok(Answer::Int(1707)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day16>(INPUT, Part::One), Ok(Answer::Int(1751)));
+    }
+
+    #[test]
+    fn raw_puzzle_format_matches_compact_format() {
+        assert_eq!(
+            solve::<Day16>(RAW_EXAMPLE, Part::One),
+            Ok(Answer::Int(1651))
+        );
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                     Valve BB has flow rate=5; tunnel leads to valve AA";
+        assert_eq!(solve::<Day16>(input, Part::One), Ok(Answer::Int(5 * 28)));
+        assert!(Day16::parse("Valve AA has flow rate=0; tunnels to valves BB").is_err());
+    }
+
+    /// Tries every order of opening the useful valves, using all-pairs distances from Floyd-Warshall.
+    /// Part 2 takes the best release for each set of opened valves and pairs up disjoint sets.
+    fn brute_force_release(input: &str) -> (u32, u32) {
+        let valves = input
+            .lines()
+            .map(|line| line.split(':').collect::<Vec<&str>>())
+            .collect::<Vec<Vec<&str>>>();
+        let index = |name: &str| valves.iter().position(|valve| valve[0] == name).unwrap();
+        let n = valves.len();
+        let mut distances = vec![vec![u32::MAX / 2; n]; n];
+        for (i, valve) in valves.iter().enumerate() {
+            distances[i][i] = 0;
+            for other in valve[2].split(',') {
+                distances[i][index(other)] = 1;
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    distances[i][j] = distances[i][j].min(distances[i][k] + distances[k][j]);
+                }
+            }
+        }
+        let flows = valves
+            .iter()
+            .map(|valve| valve[1].parse().unwrap())
+            .collect::<Vec<u32>>();
+        let useful = (0..n).filter(|&i| flows[i] > 0).collect::<Vec<usize>>();
+
+        fn explore(
+            at: usize,
+            time: u32,
+            opened: usize,
+            released: u32,
+            (useful, flows, distances): (&[usize], &[u32], &[Vec<u32>]),
+            best: &mut HashMap<usize, u32>,
+        ) {
+            let entry = best.entry(opened).or_default();
+            *entry = (*entry).max(released);
+            for (bit, &valve) in useful.iter().enumerate() {
+                let cost = distances[at][valve] + 1;
+                if opened & (1 << bit) == 0 && cost < time {
+                    let time = time - cost;
+                    let released = released + time * flows[valve];
+                    let graph = (useful, flows, distances);
+                    explore(valve, time, opened | (1 << bit), released, graph, best);
+                }
+            }
+        }
+
+        let graph = (useful.as_slice(), flows.as_slice(), distances.as_slice());
+        let mut alone = HashMap::new();
+        explore(index("AA"), 30, 0, 0, graph, &mut alone);
+        let mut together = HashMap::new();
+        explore(index("AA"), 26, 0, 0, graph, &mut together);
+        let part1 = *alone.values().max().unwrap();
+        let part2 = together
+            .iter()
+            .flat_map(|(a, ra)| {
+                together
+                    .iter()
+                    .filter(move |(b, _)| *a & *b == 0)
+                    .map(move |(_, rb)| ra + rb)
+            })
+            .max()
+            .unwrap();
+        (part1, part2)
+    }
+
+    proptest! {
+        // Part 2 takes about a second on each generated network, so run fewer cases than usual.
+        #![proptest_config(ProptestConfig::with_cases(16))]
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..30usize) {
+            let input = generate::<Day16>(seed, size);
+            let (part1, part2) = brute_force_release(&input);
+            prop_assert_eq!(solve::<Day16>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day16>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
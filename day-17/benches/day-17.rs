@@ -0,0 +1 @@
+common::bench_day!("day-17", day_17::Day17);
@@ -0,0 +1,357 @@
+use common::{
+    answer::Answer,
+    generate::{Generator, Rng, StdRng},
+    parse::ParseError,
+    pos::MapPosComp,
+    solution::{Part, Solution},
+    visualize::{Frame, Observer},
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone)]
+pub enum GustDirection {
+    Left,
+    Right,
+}
+
+struct RepeatingSequence<T> {
+    sequence: Vec<T>,
+    current_index: usize,
+}
+
+impl<T> RepeatingSequence<T> {
+    fn new(sequence: Vec<T>) -> RepeatingSequence<T> {
+        RepeatingSequence {
+            sequence,
+            current_index: 0,
+        }
+    }
+
+    fn next_item(&mut self) -> &T {
+        let previous_index = self.current_index;
+        self.advance_index();
+
+        (&self.sequence[previous_index]) as _
+    }
+
+    fn advance_index(&mut self) {
+        self.current_index = (self.current_index + 1) % self.sequence.len();
+    }
+}
+
+struct Rock {
+    body_points: Vec<(u8, u8)>,
+}
+
+impl Rock {
+    fn new(body_points: Vec<(u8, u8)>) -> Rock {
+        Rock { body_points }
+    }
+}
+
+struct Chamber {
+    width: u8,
+    points: HashSet<(u8, u64)>,
+    highest_point_y: u64,
+}
+
+impl Chamber {
+    fn new(width: u8) -> Chamber {
+        Chamber {
+            width,
+            points: HashSet::new(),
+            highest_point_y: 0,
+        }
+    }
+
+    fn drop_rock(
+        &mut self,
+        rocks: &mut RepeatingSequence<Rock>,
+        gusts: &mut RepeatingSequence<GustDirection>,
+    ) {
+        let rock = rocks.next_item();
+        let mut rock_pos = self.get_new_rock_origin();
+        loop {
+            let gust_direction = gusts.next_item();
+            let gust_offset = match gust_direction {
+                GustDirection::Left => -1,
+                GustDirection::Right => 1,
+            };
+            let mut x_offset = gust_offset;
+            let potential_rock_pos = (rock_pos.0 as i8 + x_offset, rock_pos.1);
+            for body_point in &rock.body_points {
+                let potential_body_point = (
+                    potential_rock_pos.0 + body_point.0 as i8,
+                    potential_rock_pos.1 + body_point.1 as u64,
+                );
+                if potential_body_point.0 < 0 {
+                    x_offset = 0;
+                    break;
+                }
+                let potential_body_point = (potential_body_point.0 as u8, potential_body_point.1);
+                if potential_body_point.0 >= self.width
+                    || self.points.contains(&potential_body_point)
+                {
+                    x_offset = 0;
+                    break;
+                }
+            }
+            rock_pos = ((rock_pos.0 as i8 + x_offset) as u8, rock_pos.1);
+            let mut collision_found = false;
+            if rock_pos.1 == 0 {
+                collision_found = true;
+            }
+            let mut potential_rock_pos = (rock_pos.0, rock_pos.1);
+            if !collision_found {
+                potential_rock_pos = (rock_pos.0, rock_pos.1 - 1);
+                for body_point in &rock.body_points {
+                    let potential_body_point = (
+                        potential_rock_pos.0 + body_point.0,
+                        potential_rock_pos.1 + body_point.1 as u64,
+                    );
+                    if self.points.contains(&potential_body_point) {
+                        collision_found = true;
+                        break;
+                    }
+                }
+            }
+            if collision_found {
+                self.insert_rock(rock, rock_pos);
+                break;
+            }
+            rock_pos = potential_rock_pos;
+        }
+    }
+
+    /// Draws the top of the tower, with rock as `#`, the chamber walls as `|` and the floor as `-`
+    /// once it's in view. Heights are flipped, since frames count rows downwards.
+    fn draw(&self) -> Frame {
+        let top = self.highest_point_y + 3;
+        let bottom = top.saturating_sub(DRAWN_ROWS);
+        let right = self.width as MapPosComp;
+        let floor = if bottom == 0 {
+            1
+        } else {
+            -(bottom as MapPosComp)
+        };
+        let mut frame = Frame::new(((-1, -(top as MapPosComp)), (right, floor)), '.');
+        for y in bottom..=top {
+            frame.set((-1, -(y as MapPosComp)), '|');
+            frame.set((right, -(y as MapPosComp)), '|');
+        }
+        for &(x, y) in self.points.iter().filter(|point| point.1 >= bottom) {
+            frame.set((x as MapPosComp, -(y as MapPosComp)), '#');
+        }
+        if bottom == 0 {
+            for x in -1..=right {
+                frame.set((x, floor), '-');
+            }
+            frame.set((-1, floor), '+');
+            frame.set((right, floor), '+');
+        }
+        return frame;
+    }
+
+    /// Packs the top `count` rows of the tower into one bitmask per row, so states of the chamber can
+    /// be compared without comparing every rock that has fallen.
+    fn top_rows(&self, count: u64) -> Vec<u8> {
+        let bottom = self.highest_point_y.saturating_sub(count);
+        (bottom..self.highest_point_y)
+            .map(|y| {
+                (0..self.width)
+                    .filter(|&x| self.points.contains(&(x, y)))
+                    .fold(0, |row, x| row | 1 << x)
+            })
+            .collect()
+    }
+
+    fn get_new_rock_origin(&self) -> (u8, u64) {
+        (2, self.highest_point_y + 3)
+    }
+
+    fn insert_rock(&mut self, rock: &Rock, origin: (u8, u64)) {
+        for body_point in &rock.body_points {
+            let point = (origin.0 + body_point.0, origin.1 + body_point.1 as u64);
+            self.points.insert(point);
+            if (point.1 + 1) > self.highest_point_y {
+                self.highest_point_y = point.1 + 1;
+            }
+        }
+    }
+}
+
+/// How many rows below the top of the tower are drawn when watching it grow.
+const DRAWN_ROWS: u64 = 40;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<GustDirection>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let gusts = input.trim();
+        gusts
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(GustDirection::Left),
+                '>' => Ok(GustDirection::Right),
+                _ => Err(ParseError::new(
+                    input,
+                    &gusts[i..i + c.len_utf8()],
+                    "expected < or >",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(gusts: &Self::Input<'_>) -> Answer {
+        Self::watch(gusts, Part::One, &mut ())
+    }
+
+    fn part2(gusts: &Self::Input<'_>) -> Answer {
+        Self::watch(gusts, Part::Two, &mut ())
+    }
+
+    fn watch(gusts: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        match part {
+            Part::One => stack_rocks(gusts, 2022, observer).into(),
+            Part::Two => stack_rocks_with_cycles(gusts, 1_000_000_000_000, observer).into(),
+        }
+    }
+}
+
+impl Generator for Day17 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| if rng.gen() { '<' } else { '>' })
+            .collect()
+    }
+}
+
+/// Drops `target` rocks into the chamber, returning the height of the tower they make.
+fn stack_rocks(gusts: &[GustDirection], target: u64, observer: &mut dyn Observer) -> u64 {
+    let mut gusts = RepeatingSequence::new(gusts.to_vec());
+    let mut rocks = get_rock_sequence();
+    let mut chamber = Chamber::new(7);
+    for _ in 0_u64..target {
+        chamber.drop_rock(&mut rocks, &mut gusts);
+        observer.step(&|| chamber.draw());
+    }
+    chamber.highest_point_y
+}
+
+/// How many rows from the top of the tower are compared when looking for a repeat.
+const SURFACE_ROWS: u64 = 32;
+
+/// The next rock, the next gust and the top of the tower, which together decide how the tower grows.
+type CycleKey = (usize, usize, Vec<u8>);
+
+/// Finds how tall the tower is after `target` rocks by dropping rocks until the growth of the tower
+/// repeats, then skipping ahead over the repeats.
+fn stack_rocks_with_cycles(
+    gusts: &[GustDirection],
+    target: u64,
+    observer: &mut dyn Observer,
+) -> u64 {
+    let mut chamber = Chamber::new(7);
+    let mut gusts = RepeatingSequence::new(gusts.to_vec());
+    let mut rocks = get_rock_sequence();
+    let mut cycle_heights: HashMap<CycleKey, Vec<u64>> = HashMap::new();
+    let mut cycle_indices: HashMap<CycleKey, Vec<u64>> = HashMap::new();
+    let mut dropped = 0_u64;
+    let (cycle_length, cycle_height_diff) = loop {
+        if dropped == target {
+            return chamber.highest_point_y;
+        }
+        chamber.drop_rock(&mut rocks, &mut gusts);
+        observer.step(&|| chamber.draw());
+        dropped += 1;
+        let key = (
+            rocks.current_index,
+            gusts.current_index,
+            chamber.top_rows(SURFACE_ROWS),
+        );
+        let indices = cycle_indices.entry(key.clone()).or_default();
+        indices.push(dropped);
+        let heights = cycle_heights.entry(key).or_default();
+        heights.push(chamber.highest_point_y);
+        let differences = heights
+            .windows(2)
+            .zip(indices.windows(2))
+            .map(|(heights, indices)| (indices[1] - indices[0], heights[1] - heights[0]))
+            .collect::<Vec<(u64, u64)>>();
+        if differences.len() > 1 && differences.iter().all(|diff| *diff == differences[0]) {
+            break differences[0];
+        }
+    };
+    let cycles_total = (target - dropped) / cycle_length;
+    let leftover_rocks = (target - dropped) % cycle_length;
+    for _ in 0..leftover_rocks {
+        chamber.drop_rock(&mut rocks, &mut gusts);
+        observer.step(&|| chamber.draw());
+    }
+    cycles_total * cycle_height_diff + chamber.highest_point_y
+}
+
+fn get_rock_sequence() -> RepeatingSequence<Rock> {
+    RepeatingSequence::new(
+        [
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        ]
+        .iter()
+        .map(|pieces| Rock::new(pieces.to_vec()))
+        .collect::<Vec<Rock>>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day17>(EXAMPLE, Part::One), Ok(Answer::Int(3068)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day17>(EXAMPLE, Part::Two),
+            Ok(Answer::Int(1514285714288))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day17>(INPUT, Part::One), Ok(Answer::Int(3188)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day17>(INPUT, Part::Two),
+            Ok(Answer::Int(1591977077342))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn skipping_cycles_matches_dropping_every_rock(seed: u64, size in 1..40usize, target in 3000..6000u64) {
+            let gusts = Day17::parse(&generate::<Day17>(seed, size)).unwrap();
+            prop_assert_eq!(
+                stack_rocks_with_cycles(&gusts, target, &mut ()),
+                stack_rocks(&gusts, target, &mut ())
+            );
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-18", day_18::Day18);
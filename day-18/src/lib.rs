@@ -0,0 +1,245 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::lines,
+    parse::{parse_num, parse_num_pair, split_pair, ParseError},
+    search::bfs,
+    solution::Solution,
+};
+use std::collections::HashSet;
+
+const FACE_OFFSETS: [Pos; 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+type PosComp = i8;
+
+type Pos = (PosComp, PosComp, PosComp);
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = HashSet<Pos>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let line = line.trim();
+                let (x, rest) = split_pair(input, line, ",")?;
+                let (y, z) = parse_num_pair(input, rest, ",")?;
+                Ok((parse_num(input, x)?, y, z))
+            })
+            .collect()
+    }
+
+    fn part1(cubes: &Self::Input<'_>) -> Answer {
+        let total_exposed_faces = calculate_total_exposed_faces(cubes);
+        total_exposed_faces.into()
+    }
+
+    fn part2(cubes: &Self::Input<'_>) -> Answer {
+        let outside_air_cubes = calculate_outside_air_cubes(cubes);
+        let outside_exposed_faces =
+            calculate_faces_exposed_to_outside_air(cubes, &outside_air_cubes);
+        outside_exposed_faces.into()
+    }
+}
+
+impl Generator for Day18 {
+    /// Scatters cubes through a space small enough for them to be packed together, leaving pockets of
+    /// trapped air.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let side = ((size * 2) as f64).cbrt().ceil().min(100.0) as PosComp + 1;
+        let mut cubes = Vec::new();
+        let mut placed = HashSet::new();
+        while cubes.len() < size.min((side as usize).pow(3)) {
+            let cube = (
+                rng.gen_range(0..side),
+                rng.gen_range(0..side),
+                rng.gen_range(0..side),
+            );
+            if placed.insert(cube) {
+                cubes.push(format!("{},{},{}", cube.0, cube.1, cube.2));
+            }
+        }
+        join_lines(cubes)
+    }
+}
+
+fn calculate_total_exposed_faces(cubes: &HashSet<Pos>) -> usize {
+    let mut total_exposed_faces = 0;
+    for cube in cubes {
+        let mut exposed_faces = 0;
+        for offset in &FACE_OFFSETS {
+            let offset_pos = (cube.0 + offset.0, cube.1 + offset.1, cube.2 + offset.2);
+            if !cubes.contains(&offset_pos) {
+                exposed_faces += 1;
+            }
+        }
+        total_exposed_faces += exposed_faces;
+    }
+    total_exposed_faces
+}
+
+fn calculate_outside_air_cubes(cubes: &HashSet<Pos>) -> HashSet<Pos> {
+    let mut min_xyz = (PosComp::MAX, PosComp::MAX, PosComp::MAX);
+    let mut max_xyz = (PosComp::MIN, PosComp::MIN, PosComp::MIN);
+    for cube in cubes {
+        min_xyz.0 = min_xyz.0.min(cube.0 - 1);
+        min_xyz.1 = min_xyz.1.min(cube.1 - 1);
+        min_xyz.2 = min_xyz.2.min(cube.2 - 1);
+        max_xyz.0 = max_xyz.0.max(cube.0 + 1);
+        max_xyz.1 = max_xyz.1.max(cube.1 + 1);
+        max_xyz.2 = max_xyz.2.max(cube.2 + 1);
+    }
+    let search = bfs(
+        [min_xyz],
+        |current_air_cube| {
+            FACE_OFFSETS
+                .iter()
+                .map(|offset| {
+                    (
+                        current_air_cube.0 + offset.0,
+                        current_air_cube.1 + offset.1,
+                        current_air_cube.2 + offset.2,
+                    )
+                })
+                .filter(|adjacent_cube| {
+                    !cubes.contains(adjacent_cube)
+                        && !point_is_outside_bounds(adjacent_cube, &min_xyz, &max_xyz)
+                })
+                .collect::<Vec<Pos>>()
+        },
+        |_| false,
+    );
+    search.distances().keys().copied().collect()
+}
+
+fn calculate_faces_exposed_to_outside_air(
+    cubes: &HashSet<Pos>,
+    outside_air_cubes: &HashSet<Pos>,
+) -> usize {
+    let mut total_exposed_faces = 0;
+    for cube in cubes {
+        let mut exposed_faces = 0;
+        for offset in &FACE_OFFSETS {
+            if outside_air_cubes.contains(&(
+                cube.0 + offset.0,
+                cube.1 + offset.1,
+                cube.2 + offset.2,
+            )) {
+                exposed_faces += 1;
+            }
+        }
+        total_exposed_faces += exposed_faces;
+    }
+    total_exposed_faces
+}
+
+fn point_is_outside_bounds(point: &Pos, min_xyz: &Pos, max_xyz: &Pos) -> bool {
+    point.0 < min_xyz.0
+        || point.0 > max_xyz.0
+        || point.1 < min_xyz.1
+        || point.1 > max_xyz.1
+        || point.2 < min_xyz.2
+        || point.2 > max_xyz.2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day18>(EXAMPLE, Part::One), Ok(Answer::Int(64)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day18>(EXAMPLE, Part::Two), Ok(Answer::Int(58)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day18>(INPUT, Part::One), Ok(Answer::Int(4456)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day18>(INPUT, Part::Two), Ok(Answer::Int(2510)));
+    }
+
+    /// Counts touching pairs of cubes for part 1, and for part 2 fills a padded box of air from one
+    /// corner, counting every cube face the air reaches.
+    fn brute_force_surface(input: &str) -> (usize, usize) {
+        let cubes = input
+            .lines()
+            .map(|line| {
+                let coords = line.split(',').map(|n| n.parse::<usize>().unwrap() + 1);
+                coords.collect::<Vec<usize>>()
+            })
+            .collect::<Vec<Vec<usize>>>();
+        let touching = cubes
+            .iter()
+            .flat_map(|a| cubes.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.abs_diff(*b))
+                    .sum::<usize>()
+                    == 1
+            })
+            .count();
+        let part1 = 6 * cubes.len() - touching;
+
+        let side = cubes.iter().flatten().max().unwrap() + 2;
+        let mut solid = vec![vec![vec![false; side]; side]; side];
+        for cube in &cubes {
+            solid[cube[0]][cube[1]][cube[2]] = true;
+        }
+        let mut air = vec![vec![vec![false; side]; side]; side];
+        air[0][0][0] = true;
+        let mut stack = vec![[0_usize; 3]];
+        let mut part2 = 0;
+        while let Some(point) = stack.pop() {
+            for axis in 0..3 {
+                for neighbor in [point[axis].wrapping_sub(1), point[axis] + 1] {
+                    if neighbor >= side {
+                        continue;
+                    }
+                    let mut next = point;
+                    next[axis] = neighbor;
+                    if solid[next[0]][next[1]][next[2]] {
+                        part2 += 1;
+                    } else if !air[next[0]][next[1]][next[2]] {
+                        air[next[0]][next[1]][next[2]] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+        (part1, part2)
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..400usize) {
+            let input = generate::<Day18>(seed, size);
+            let (part1, part2) = brute_force_surface(&input);
+            prop_assert_eq!(solve::<Day18>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day18>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
@@ -0,0 +1,2 @@
+// Part 2 takes minutes per run, far too long for criterion to sample, so only part 1 is timed.
+common::bench_day!("day-19", day_19::Day19, [common::solution::Part::One]);
@@ -0,0 +1,581 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::lines,
+    parse::{parse_num, parse_num_pair, split_pair, strip_literal, ParseError},
+    search::SearchStats,
+    solution::{ConfiguredSolution, Solution},
+};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+type ResourceValue = u16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ResourceAmount {
+    ore: ResourceValue,
+    clay: ResourceValue,
+    obsidian: ResourceValue,
+    geode: ResourceValue,
+}
+
+impl Add for ResourceAmount {
+    type Output = ResourceAmount;
+
+    fn add(self, other: ResourceAmount) -> ResourceAmount {
+        ResourceAmount {
+            ore: self.ore + other.ore,
+            clay: self.clay + other.clay,
+            obsidian: self.obsidian + other.obsidian,
+            geode: self.geode + other.geode,
+        }
+    }
+}
+
+impl Sub for ResourceAmount {
+    type Output = ResourceAmount;
+
+    fn sub(self, other: ResourceAmount) -> ResourceAmount {
+        ResourceAmount {
+            ore: self.ore - other.ore,
+            clay: self.clay - other.clay,
+            obsidian: self.obsidian - other.obsidian,
+            geode: self.geode - other.geode,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Blueprint {
+    ore_robot_cost: ResourceAmount,
+    clay_robot_cost: ResourceAmount,
+    obsidian_robot_cost: ResourceAmount,
+    geode_robot_cost: ResourceAmount,
+}
+
+#[derive(Clone)]
+struct RobotState {
+    time_left: u8,
+    ore_robots: ResourceValue,
+    clay_robots: ResourceValue,
+    obsidian_robots: ResourceValue,
+    geode_robots: ResourceValue,
+    resources: ResourceAmount,
+}
+
+#[derive(Clone)]
+enum RobotTypes {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl RobotTypes {
+    fn unneeded_reason(&self) -> &'static str {
+        match self {
+            RobotTypes::Ore => "unneeded ore robots",
+            RobotTypes::Clay => "unneeded clay robots",
+            RobotTypes::Obsidian => "unneeded obsidian robots",
+            RobotTypes::Geode => "unneeded geode robots",
+        }
+    }
+}
+
+impl RobotState {
+    fn new(time_left: u8) -> RobotState {
+        RobotState {
+            time_left,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            resources: ResourceAmount {
+                ore: 0,
+                clay: 0,
+                obsidian: 0,
+                geode: 0,
+            },
+        }
+    }
+
+    fn get_possible_final_geodes(&self, blueprint: &Blueprint) -> Vec<ResourceValue> {
+        if self.is_done() {
+            return vec![self.resources.geode];
+        }
+        let _span = tracing::info_span!("geode_search", time_left = self.time_left).entered();
+        let mut stats = SearchStats::new();
+        let mut successors = self.get_successors(blueprint, &mut stats);
+        let mut final_geode_counts = Vec::new();
+        while let Some(successor) = successors.pop() {
+            if successor.is_done() {
+                final_geode_counts.push(successor.resources.geode);
+            } else {
+                stats.expand(successors.len());
+                let new_successors = successor.get_successors(blueprint, &mut stats);
+                successors.extend(new_successors);
+            }
+        }
+        stats.finish(stats.expanded as usize + final_geode_counts.len());
+        final_geode_counts
+    }
+
+    fn is_done(&self) -> bool {
+        self.time_left == 0
+    }
+
+    fn get_successors(&self, blueprint: &Blueprint, stats: &mut SearchStats) -> Vec<RobotState> {
+        let mut successors = Vec::new();
+        if self.is_done() {
+            return successors;
+        }
+        let buildable_robot_types = self
+            .get_useful_buildable_robot_types(blueprint, |robot_type| {
+                stats.prune(robot_type.unneeded_reason())
+            });
+        for robot_type in &buildable_robot_types {
+            match robot_type {
+                RobotTypes::Ore => successors.push(self.get_build_ore_robot_successor(blueprint)),
+                RobotTypes::Clay => successors.push(self.get_build_clay_robot_successor(blueprint)),
+                RobotTypes::Obsidian => {
+                    successors.push(self.get_build_obsidian_robot_successor(blueprint))
+                }
+                RobotTypes::Geode => {
+                    successors.push(self.get_build_geode_robot_successor(blueprint))
+                }
+            }
+        }
+        let mut current_do_nothing_successor = self.get_do_nothing_successor();
+        let mut current_buildable_robot_types =
+            current_do_nothing_successor.get_useful_buildable_robot_types(blueprint, |_| {});
+        while current_buildable_robot_types.len() == buildable_robot_types.len()
+            && !current_do_nothing_successor.is_done()
+        {
+            stats.prune("idle minutes skipped");
+            current_do_nothing_successor = current_do_nothing_successor.get_do_nothing_successor();
+            current_buildable_robot_types =
+                current_do_nothing_successor.get_useful_buildable_robot_types(blueprint, |_| {});
+        }
+        successors.push(current_do_nothing_successor);
+        return successors;
+    }
+
+    /// The robots that can be built now and would add to an output that's still short of what's
+    /// needed, calling `on_unneeded` with each buildable robot that wouldn't.
+    fn get_useful_buildable_robot_types(
+        &self,
+        blueprint: &Blueprint,
+        mut on_unneeded: impl FnMut(&RobotTypes),
+    ) -> Vec<RobotTypes> {
+        let mut need_more_ore_robots =
+            self.get_current_ore_output() < blueprint.geode_robot_cost.ore;
+        let mut need_more_clay_robots =
+            self.get_current_clay_output() < blueprint.geode_robot_cost.clay;
+        let need_more_obsidian_robots =
+            self.get_current_obsidian_output() < blueprint.geode_robot_cost.obsidian;
+        if need_more_obsidian_robots {
+            need_more_ore_robots =
+                self.get_current_ore_output() < blueprint.obsidian_robot_cost.ore;
+            need_more_clay_robots =
+                self.get_current_clay_output() < blueprint.obsidian_robot_cost.clay;
+            if need_more_clay_robots {
+                need_more_ore_robots =
+                    self.get_current_ore_output() < blueprint.clay_robot_cost.ore;
+            }
+        }
+        let robot_types = self.get_buildable_robot_types(blueprint);
+        robot_types
+            .iter()
+            .filter(|robot_type| {
+                let needed = match robot_type {
+                    RobotTypes::Ore => need_more_ore_robots,
+                    RobotTypes::Clay => need_more_clay_robots,
+                    RobotTypes::Obsidian => need_more_obsidian_robots,
+                    RobotTypes::Geode => true,
+                };
+                if !needed {
+                    on_unneeded(robot_type);
+                }
+                needed
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn get_buildable_robot_types(&self, blueprint: &Blueprint) -> Vec<RobotTypes> {
+        let mut robot_types = Vec::new();
+        if self.can_build_ore_robot(&blueprint.ore_robot_cost) {
+            robot_types.push(RobotTypes::Ore);
+        }
+        if self.can_build_clay_robot(&blueprint.clay_robot_cost) {
+            robot_types.push(RobotTypes::Clay);
+        }
+        if self.can_build_obsidian_robot(&blueprint.obsidian_robot_cost) {
+            robot_types.push(RobotTypes::Obsidian);
+        }
+        if self.can_build_geode_robot(&blueprint.geode_robot_cost) {
+            robot_types.push(RobotTypes::Geode);
+        }
+        robot_types
+    }
+
+    fn get_do_nothing_successor(&self) -> RobotState {
+        RobotState {
+            time_left: self.time_left - 1,
+            ore_robots: self.ore_robots,
+            clay_robots: self.clay_robots,
+            obsidian_robots: self.obsidian_robots,
+            geode_robots: self.geode_robots,
+            resources: self.resources + self.get_current_resource_output(),
+        }
+    }
+
+    fn get_build_ore_robot_successor(&self, blueprint: &Blueprint) -> RobotState {
+        RobotState {
+            time_left: self.time_left - 1,
+            ore_robots: self.ore_robots + 1,
+            clay_robots: self.clay_robots,
+            obsidian_robots: self.obsidian_robots,
+            geode_robots: self.geode_robots,
+            resources: self.resources + self.get_current_resource_output()
+                - blueprint.ore_robot_cost,
+        }
+    }
+
+    fn get_build_clay_robot_successor(&self, blueprint: &Blueprint) -> RobotState {
+        RobotState {
+            time_left: self.time_left - 1,
+            ore_robots: self.ore_robots,
+            clay_robots: self.clay_robots + 1,
+            obsidian_robots: self.obsidian_robots,
+            geode_robots: self.geode_robots,
+            resources: self.resources + self.get_current_resource_output()
+                - blueprint.clay_robot_cost,
+        }
+    }
+
+    fn get_build_obsidian_robot_successor(&self, blueprint: &Blueprint) -> RobotState {
+        RobotState {
+            time_left: self.time_left - 1,
+            ore_robots: self.ore_robots,
+            clay_robots: self.clay_robots,
+            obsidian_robots: self.obsidian_robots + 1,
+            geode_robots: self.geode_robots,
+            resources: self.resources + self.get_current_resource_output()
+                - blueprint.obsidian_robot_cost,
+        }
+    }
+
+    fn get_build_geode_robot_successor(&self, blueprint: &Blueprint) -> RobotState {
+        RobotState {
+            time_left: self.time_left - 1,
+            ore_robots: self.ore_robots,
+            clay_robots: self.clay_robots,
+            obsidian_robots: self.obsidian_robots,
+            geode_robots: self.geode_robots + 1,
+            resources: self.resources + self.get_current_resource_output()
+                - blueprint.geode_robot_cost,
+        }
+    }
+
+    fn can_build_ore_robot(&self, cost: &ResourceAmount) -> bool {
+        self.resources.ore >= cost.ore
+    }
+
+    fn can_build_clay_robot(&self, cost: &ResourceAmount) -> bool {
+        self.resources.ore >= cost.ore
+    }
+
+    fn can_build_obsidian_robot(&self, cost: &ResourceAmount) -> bool {
+        self.resources.ore >= cost.ore && self.resources.clay >= cost.clay
+    }
+
+    fn can_build_geode_robot(&self, cost: &ResourceAmount) -> bool {
+        self.resources.ore >= cost.ore && self.resources.obsidian >= cost.obsidian
+    }
+
+    fn get_current_ore_output(&self) -> ResourceValue {
+        self.ore_robots
+    }
+
+    fn get_current_clay_output(&self) -> ResourceValue {
+        self.clay_robots
+    }
+
+    fn get_current_obsidian_output(&self) -> ResourceValue {
+        self.obsidian_robots
+    }
+
+    fn get_current_geode_output(&self) -> ResourceValue {
+        self.geode_robots
+    }
+
+    fn get_current_resource_output(&self) -> ResourceAmount {
+        ResourceAmount {
+            ore: self.get_current_ore_output(),
+            clay: self.get_current_clay_output(),
+            obsidian: self.get_current_obsidian_output(),
+            geode: self.get_current_geode_output(),
+        }
+    }
+}
+
+pub struct Day19;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day19Config {
+    /// How long part 1 has to crack geodes with each blueprint.
+    pub part1_minutes: u8,
+    /// How long part 2 has to crack geodes with each of its blueprints.
+    pub part2_minutes: u8,
+    /// How many blueprints, from the first, part 2 multiplies the geode counts of.
+    pub part2_blueprints: usize,
+}
+
+impl Default for Day19Config {
+    fn default() -> Self {
+        Day19Config {
+            part1_minutes: 24,
+            part2_minutes: 32,
+            part2_blueprints: 3,
+        }
+    }
+}
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        if input.trim_start().starts_with("Blueprint") {
+            return parse_raw_blueprints(input);
+        }
+        lines(input)
+            .map(|line| {
+                let line = line.trim();
+                let costs = line.split(":").collect::<Vec<&str>>();
+                if costs.len() != 4 {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        "expected four robot costs separated by colons",
+                    ));
+                }
+                let ore_robot_cost = ResourceAmount {
+                    ore: parse_num(input, costs[0])?,
+                    clay: 0,
+                    obsidian: 0,
+                    geode: 0,
+                };
+                let clay_robot_cost = ResourceAmount {
+                    ore: parse_num(input, costs[1])?,
+                    clay: 0,
+                    obsidian: 0,
+                    geode: 0,
+                };
+                let (ore, clay) = parse_num_pair(input, costs[2], ",")?;
+                let obsidian_robot_cost = ResourceAmount {
+                    ore,
+                    clay,
+                    obsidian: 0,
+                    geode: 0,
+                };
+                let (ore, obsidian) = parse_num_pair(input, costs[3], ",")?;
+                let geode_robot_cost = ResourceAmount {
+                    ore,
+                    clay: 0,
+                    obsidian,
+                    geode: 0,
+                };
+                Ok(Blueprint {
+                    ore_robot_cost,
+                    clay_robot_cost,
+                    obsidian_robot_cost,
+                    geode_robot_cost,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(blueprints: &Self::Input<'_>) -> Answer {
+        Self::part1_with(blueprints, &Day19Config::default())
+    }
+
+    fn part2(blueprints: &Self::Input<'_>) -> Answer {
+        Self::part2_with(blueprints, &Day19Config::default())
+    }
+}
+
+impl ConfiguredSolution for Day19 {
+    type Config = Day19Config;
+
+    fn part1_with(blueprints: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let initial_state = RobotState::new(config.part1_minutes);
+        let mut total_quality = 0;
+        for (i, blueprint) in blueprints.iter().enumerate() {
+            let blueprint_number = i + 1;
+            let _span = tracing::info_span!("blueprint", number = blueprint_number).entered();
+            let final_geode_counts = initial_state.get_possible_final_geodes(blueprint);
+            let max_geode_count = *final_geode_counts.iter().max().unwrap();
+            let quality = max_geode_count as usize * blueprint_number;
+            total_quality += quality;
+        }
+        total_quality.into()
+    }
+
+    fn part2_with(blueprints: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let initial_state = RobotState::new(config.part2_minutes);
+        let mut highest_geode_counts = vec![];
+        let blueprints = blueprints.iter().take(config.part2_blueprints);
+        for (i, blueprint) in blueprints.enumerate() {
+            let _span = tracing::info_span!("blueprint", number = i + 1).entered();
+            let final_geode_counts = initial_state.get_possible_final_geodes(blueprint);
+            let max_geode_count = final_geode_counts.iter().max().unwrap();
+            highest_geode_counts.push(*max_geode_count as usize);
+        }
+        highest_geode_counts.iter().product::<usize>().into()
+    }
+}
+
+/// Parses blueprints as the puzzle gives them, like `Blueprint 1: Each ore robot costs 4 ore. Each
+/// clay robot costs 2 ore. ...`, whether each one is on a line of its own or wrapped over several.
+fn parse_raw_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let starts = input
+        .match_indices("Blueprint")
+        .map(|(start, _)| start)
+        .collect::<Vec<usize>>();
+    let ends = starts.iter().skip(1).copied().chain([input.len()]);
+    starts
+        .iter()
+        .zip(ends)
+        .enumerate()
+        .map(|(i, (&start, end))| {
+            let text = input[start..end].trim_end();
+            let rest = strip_literal(input, text, "Blueprint ")?;
+            let (number, robots) = split_pair(input, rest, ":")?;
+            if parse_num::<usize>(input, number)? != i + 1 {
+                return Err(ParseError::new(
+                    input,
+                    number,
+                    format!("expected blueprint {}", i + 1),
+                ));
+            }
+            parse_raw_robot_costs(input, robots)
+        })
+        .collect()
+}
+
+/// Parses sentences like `Each obsidian robot costs 3 ore and 14 clay.`, one for each kind of robot.
+fn parse_raw_robot_costs(input: &str, text: &str) -> Result<Blueprint, ParseError> {
+    let mut costs = [None; 4];
+    for sentence in text.split('.').map(|sentence| sentence.trim()) {
+        if sentence.is_empty() {
+            continue;
+        }
+        let rest = strip_literal(input, sentence, "Each ")?;
+        let (robot, cost_list) = split_pair(input, rest, " robot costs ")?;
+        let robot_index = match robot {
+            "ore" => 0,
+            "clay" => 1,
+            "obsidian" => 2,
+            "geode" => 3,
+            _ => return Err(ParseError::new(input, robot, "expected a kind of robot")),
+        };
+        let mut cost = ResourceAmount {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+        };
+        for item in cost_list.split(" and ") {
+            let (amount, resource) = split_pair(input, item.trim(), " ")?;
+            let amount = parse_num(input, amount)?;
+            match resource {
+                "ore" => cost.ore = amount,
+                "clay" => cost.clay = amount,
+                "obsidian" => cost.obsidian = amount,
+                _ => {
+                    return Err(ParseError::new(
+                        input,
+                        resource,
+                        "expected ore, clay or obsidian",
+                    ))
+                }
+            }
+        }
+        costs[robot_index] = Some(cost);
+    }
+    let [Some(ore_robot_cost), Some(clay_robot_cost), Some(obsidian_robot_cost), Some(geode_robot_cost)] =
+        costs
+    else {
+        return Err(ParseError::missing(
+            input,
+            text,
+            "the cost of every kind of robot",
+        ));
+    };
+    Ok(Blueprint {
+        ore_robot_cost,
+        clay_robot_cost,
+        obsidian_robot_cost,
+        geode_robot_cost,
+    })
+}
+
+impl Generator for Day19 {
+    /// Makes blueprints with costs in the same ranges as the real input's, and always at least the
+    /// three that part 2 looks at.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        join_lines((0..size.max(3)).map(|_| {
+            format!(
+                "{}:{}:{},{}:{},{}",
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20)
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const RAW_EXAMPLE: &str = include_str!("../resources/example-raw.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    // Part 2 only looks at the first three blueprints, and the example has just two.
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day19>(EXAMPLE, Part::One), Ok(Answer::Int(33)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day19>(INPUT, Part::One), Ok(Answer::Int(1834)));
+    }
+
+    #[test]
+    #[ignore = "takes minutes even in release"]
+    fn part2_input() {
+        assert_eq!(solve::<Day19>(INPUT, Part::Two), Ok(Answer::Int(2240)));
+    }
+
+    #[test]
+    fn raw_puzzle_format_matches_compact_format() {
+        assert_eq!(Day19::parse(RAW_EXAMPLE), Day19::parse(EXAMPLE));
+        let one_per_line = RAW_EXAMPLE
+            .replace(":\n ", ":")
+            .replace(".\n ", ".")
+            .replace("\n\n", "\n");
+        assert_eq!(one_per_line.lines().count(), 2);
+        assert_eq!(Day19::parse(&one_per_line), Day19::parse(EXAMPLE));
+        assert!(Day19::parse("Blueprint 2: Each ore robot costs 4 ore.").is_err());
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-20", day_20::Day20);
@@ -0,0 +1,197 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::lines,
+    parse::{parse_num, ParseError},
+    solution::{ConfiguredSolution, Solution},
+};
+use serde::{Deserialize, Serialize};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub struct Day20;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day20Config {
+    /// What part 2 multiplies every number by before mixing.
+    pub decryption_key: i64,
+    /// How many times part 2 mixes the numbers.
+    pub mix_count: usize,
+}
+
+impl Default for Day20Config {
+    fn default() -> Self {
+        Day20Config {
+            decryption_key: DECRYPTION_KEY,
+            mix_count: 10,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|line| parse_num(input, line.trim()))
+            .collect()
+    }
+
+    fn part1(sequence: &Self::Input<'_>) -> Answer {
+        Self::part1_with(sequence, &Day20Config::default())
+    }
+
+    fn part2(sequence: &Self::Input<'_>) -> Answer {
+        Self::part2_with(sequence, &Day20Config::default())
+    }
+}
+
+impl ConfiguredSolution for Day20 {
+    type Config = Day20Config;
+
+    fn part1_with(sequence: &Self::Input<'_>, _config: &Self::Config) -> Answer {
+        let mut new_sequence = sequence.iter().collect::<Vec<&i64>>();
+        mix_sequence(sequence, &mut new_sequence);
+        calculate_sequence_answer(&new_sequence).into()
+    }
+
+    fn part2_with(sequence: &Self::Input<'_>, config: &Self::Config) -> Answer {
+        let sequence = sequence
+            .iter()
+            .map(|x| x * config.decryption_key)
+            .collect::<Vec<i64>>();
+        let mut new_sequence = sequence.iter().collect::<Vec<&i64>>();
+        for _ in 0..config.mix_count {
+            mix_sequence(&sequence, &mut new_sequence);
+        }
+        calculate_sequence_answer(&new_sequence).into()
+    }
+}
+
+impl Generator for Day20 {
+    /// Makes a file of random numbers, repeats included, with exactly one zero in it.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut numbers = (1..size.max(2))
+            .map(|_| {
+                let magnitude = rng.gen_range(1..=10000);
+                if rng.gen() {
+                    magnitude
+                } else {
+                    -magnitude
+                }
+            })
+            .collect::<Vec<i64>>();
+        numbers.insert(rng.gen_range(0..=numbers.len()), 0);
+        join_lines(numbers.iter().map(|number| number.to_string()))
+    }
+}
+
+fn mix_sequence<'a>(original_sequence: &'a [i64], new_sequence: &mut Vec<&'a i64>) {
+    let len = original_sequence.len() as i64;
+    let mut i = 0;
+    while i < len {
+        let num = &original_sequence[i as usize];
+        let num_position_in_new_sequence = new_sequence
+            .iter()
+            .position(|x| std::ptr::eq(*x, num))
+            .unwrap();
+        let new_position = (num_position_in_new_sequence as i64 + *num) % (len - 1);
+        let new_position = if new_position <= 0 {
+            (len - 1) + new_position
+        } else {
+            new_position
+        };
+        let new_position = new_position as usize;
+        new_sequence.remove(num_position_in_new_sequence);
+        new_sequence.insert(new_position, num);
+        i += 1;
+    }
+}
+
+fn calculate_sequence_answer(sequence: &[&i64]) -> i64 {
+    let zero_pos = sequence.iter().position(|x| **x == 0).unwrap();
+    let answer = [1000, 2000, 3000]
+        .iter()
+        .map(|offset| (zero_pos + offset) % sequence.len())
+        .map(|pos| *sequence[pos])
+        .sum::<i64>();
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day20>(EXAMPLE, Part::One), Ok(Answer::Int(3)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day20>(EXAMPLE, Part::Two),
+            Ok(Answer::Int(1623178306))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day20>(INPUT, Part::One), Ok(Answer::Int(10763)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day20>(INPUT, Part::Two),
+            Ok(Answer::Int(4979911042808))
+        );
+    }
+
+    /// Mixes by swapping each number with its neighbour one step at a time, around the circle.
+    fn brute_force_mix(numbers: &[i64], rounds: usize) -> i64 {
+        let n = numbers.len();
+        let mut circle = numbers
+            .iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<(usize, i64)>>();
+        for _ in 0..rounds {
+            for (original, &number) in numbers.iter().enumerate() {
+                let mut pos = circle.iter().position(|&(i, _)| i == original).unwrap();
+                let steps = if n > 1 {
+                    number.rem_euclid(n as i64 - 1)
+                } else {
+                    0
+                };
+                for _ in 0..steps {
+                    circle.swap(pos, (pos + 1) % n);
+                    pos = (pos + 1) % n;
+                }
+            }
+        }
+        let zero = circle.iter().position(|&(_, value)| value == 0).unwrap();
+        [1000, 2000, 3000]
+            .iter()
+            .map(|offset| circle[(zero + offset) % n].1)
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..100usize) {
+            let input = generate::<Day20>(seed, size);
+            let numbers = Day20::parse(&input).unwrap();
+            let decrypted = numbers.iter().map(|x| x * DECRYPTION_KEY).collect::<Vec<i64>>();
+            prop_assert_eq!(solve::<Day20>(&input, Part::One), Ok(Answer::from(brute_force_mix(&numbers, 1))));
+            prop_assert_eq!(solve::<Day20>(&input, Part::Two), Ok(Answer::from(brute_force_mix(&decrypted, 10))));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-21", day_21::Day21);
@@ -0,0 +1,520 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::{split_pair, ParseError},
+    solution::Solution,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+type MonkeyBusiness<'a> = HashMap<MonkeyName<'a>, MonkeyAction<'a>>;
+
+type MonkeyName<'a> = &'a str;
+
+pub enum MonkeyAction<'a> {
+    YellValue(MonkeyValue),
+    DoOperation(MonkeyOperation<'a>),
+}
+
+type MonkeyValue = i128;
+
+pub struct MonkeyOperation<'a> {
+    operator: ArithmeticOperator,
+    operand_1: MonkeyName<'a>,
+    operand_2: MonkeyName<'a>,
+}
+
+#[derive(Clone, Copy)]
+enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+struct EqualityEquation {
+    left_side: ArithmeticExpression,
+    right_side: ArithmeticExpression,
+}
+
+#[derive(Clone)]
+enum ArithmeticExpression {
+    Value(MonkeyValue),
+    FreeVariable,
+    Operation(Box<ArithmeticOperation>),
+}
+
+#[derive(Clone)]
+struct ArithmeticOperation {
+    operator: ArithmeticOperator,
+    operand_1: ArithmeticExpression,
+    operand_2: ArithmeticExpression,
+}
+
+impl Add for ArithmeticExpression {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        ArithmeticExpression::Operation(Box::new(ArithmeticOperation {
+            operator: ArithmeticOperator::Add,
+            operand_1: self,
+            operand_2: other,
+        }))
+    }
+}
+
+impl Sub for ArithmeticExpression {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        ArithmeticExpression::Operation(Box::new(ArithmeticOperation {
+            operator: ArithmeticOperator::Subtract,
+            operand_1: self,
+            operand_2: other,
+        }))
+    }
+}
+
+impl Mul for ArithmeticExpression {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        ArithmeticExpression::Operation(Box::new(ArithmeticOperation {
+            operator: ArithmeticOperator::Multiply,
+            operand_1: self,
+            operand_2: other,
+        }))
+    }
+}
+
+impl Div for ArithmeticExpression {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        ArithmeticExpression::Operation(Box::new(ArithmeticOperation {
+            operator: ArithmeticOperator::Divide,
+            operand_1: self,
+            operand_2: other,
+        }))
+    }
+}
+
+impl Neg for ArithmeticExpression {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ArithmeticExpression::Operation(Box::new(ArithmeticOperation {
+            operator: ArithmeticOperator::Multiply,
+            operand_1: ArithmeticExpression::Value(-1),
+            operand_2: self,
+        }))
+    }
+}
+
+impl ArithmeticExpression {
+    fn has_free_variable(&self) -> bool {
+        match self {
+            ArithmeticExpression::Value(_) => false,
+            ArithmeticExpression::FreeVariable => true,
+            ArithmeticExpression::Operation(operation) => {
+                operation.operand_1.has_free_variable() || operation.operand_2.has_free_variable()
+            }
+        }
+    }
+
+    fn resolve_without_free_variable(&self) -> MonkeyValue {
+        match self {
+            ArithmeticExpression::Value(val) => *val,
+            ArithmeticExpression::FreeVariable => panic!("Free variable in expression!"),
+            ArithmeticExpression::Operation(operation) => {
+                let operand_1 = operation.operand_1.resolve_without_free_variable();
+                let operand_2 = operation.operand_2.resolve_without_free_variable();
+                match operation.operator {
+                    ArithmeticOperator::Add => operand_1 + operand_2,
+                    ArithmeticOperator::Subtract => operand_1 - operand_2,
+                    ArithmeticOperator::Multiply => operand_1 * operand_2,
+                    ArithmeticOperator::Divide => operand_1 / operand_2,
+                }
+            }
+        }
+    }
+
+    fn balance_free_variable_with_other(
+        &self,
+        other_expression: ArithmeticExpression,
+    ) -> ArithmeticExpression {
+        match self {
+            ArithmeticExpression::Value(_) => {
+                panic!("Value at base level of expression found when applying inverse!")
+            }
+            ArithmeticExpression::FreeVariable => other_expression,
+            ArithmeticExpression::Operation(operation) => {
+                let operation_clone = operation.clone();
+                let (free_variable_side, constant_side, free_variable_on_left) =
+                    if operation.operand_1.has_free_variable() {
+                        (operation_clone.operand_1, operation_clone.operand_2, true)
+                    } else {
+                        (operation_clone.operand_2, operation_clone.operand_1, false)
+                    };
+                let wrapped_other = match operation.operator {
+                    ArithmeticOperator::Add => other_expression - constant_side,
+                    ArithmeticOperator::Subtract => match free_variable_on_left {
+                        true => other_expression + constant_side,
+                        false => -(other_expression - constant_side),
+                    },
+                    ArithmeticOperator::Multiply => other_expression / constant_side,
+                    ArithmeticOperator::Divide => match free_variable_on_left {
+                        true => other_expression * constant_side,
+                        false => constant_side / other_expression,
+                    },
+                };
+                free_variable_side.balance_free_variable_with_other(wrapped_other)
+            }
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = MonkeyBusiness<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let monkey_business = lines(input)
+            .map(|line| {
+                let (name, action) = split_pair(input, line.trim(), ":")?;
+                let name = name.trim();
+                let action = action.trim();
+                let action = if let Ok(val) = action.parse() {
+                    MonkeyAction::YellValue(val)
+                } else {
+                    let parts = action.split(" ").collect::<Vec<&str>>();
+                    if parts.len() != 3 {
+                        return Err(ParseError::new(
+                            input,
+                            action,
+                            "expected a number or an operation",
+                        ));
+                    }
+                    let operator = match parts[1] {
+                        "+" => ArithmeticOperator::Add,
+                        "-" => ArithmeticOperator::Subtract,
+                        "*" => ArithmeticOperator::Multiply,
+                        "/" => ArithmeticOperator::Divide,
+                        _ => {
+                            return Err(ParseError::new(
+                                input,
+                                parts[1],
+                                "expected an operator: +, -, * or /",
+                            ))
+                        }
+                    };
+                    let operation = MonkeyOperation {
+                        operator,
+                        operand_1: parts[0],
+                        operand_2: parts[2],
+                    };
+                    MonkeyAction::DoOperation(operation)
+                };
+                Ok((name, action))
+            })
+            .collect::<Result<MonkeyBusiness, ParseError>>()?;
+        if !monkey_business.contains_key("root") {
+            return Err(ParseError::missing(input, input, "a root monkey"));
+        }
+        for action in monkey_business.values() {
+            if let MonkeyAction::DoOperation(operation) = action {
+                for operand in [operation.operand_1, operation.operand_2] {
+                    if !monkey_business.contains_key(operand) {
+                        return Err(ParseError::new(input, operand, "no such monkey"));
+                    }
+                }
+            }
+        }
+        Ok(monkey_business)
+    }
+
+    fn part1(monkey_business: &Self::Input<'_>) -> Answer {
+        resolve_monkey_value(monkey_business, "root").into()
+    }
+
+    fn part2(monkey_business: &Self::Input<'_>) -> Answer {
+        let root_monkey = monkey_business.get("root").unwrap();
+        let equality = match root_monkey {
+            MonkeyAction::YellValue(_) => panic!("Root monkey is not an operation"),
+            MonkeyAction::DoOperation(operation) => {
+                let left_side = resolve_monkey_expression(monkey_business, operation.operand_1);
+                let right_side = resolve_monkey_expression(monkey_business, operation.operand_2);
+                EqualityEquation {
+                    left_side,
+                    right_side,
+                }
+            }
+        };
+        let (free_variable_side, constant_side) = if equality.left_side.has_free_variable() {
+            (equality.left_side, equality.right_side)
+        } else {
+            (equality.right_side, equality.left_side)
+        };
+        let answer_p2 = free_variable_side
+            .balance_free_variable_with_other(constant_side)
+            .resolve_without_free_variable();
+        answer_p2.into()
+    }
+}
+
+impl Generator for Day21 {
+    /// Works backwards from a value for `humn` that makes root's two sides equal, building up a chain
+    /// of monkeys from `humn` to one side of root and a tree of monkeys yelling the same total on the
+    /// other. Every division comes out exact, and `humn` only ever ends up on top of one.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut monkeys = MonkeyWriter::default();
+        let depth = (size / 8).clamp(1, 40);
+        let subtree_ops = size / 2 / (depth + 1);
+        let mut side = "humn".to_owned();
+        let mut value: MonkeyValue = rng.gen_range(1..=1000);
+        monkeys.yell(&side, rng.gen_range(1..=1000));
+        for _ in 0..depth {
+            let divisors = (2..=9)
+                .filter(|&d| value != 0 && value % d == 0)
+                .collect::<Vec<MonkeyValue>>();
+            let budget = rng.gen_range(0..=subtree_ops);
+            let (operand, operator, humn_on_left) = match rng.gen_range(0..4) {
+                0 => {
+                    let operand = rng.gen_range(1..=1000);
+                    value += operand;
+                    (operand, '+', rng.gen())
+                }
+                1 => {
+                    let operand = rng.gen_range(1..=1000);
+                    let humn_on_left = rng.gen();
+                    value = if humn_on_left {
+                        value - operand
+                    } else {
+                        operand - value
+                    };
+                    (operand, '-', humn_on_left)
+                }
+                2 if !divisors.is_empty() => {
+                    let operand = *divisors.choose(rng).unwrap();
+                    value /= operand;
+                    (operand, '/', true)
+                }
+                _ => {
+                    let operand = rng.gen_range(2..=4);
+                    value *= operand;
+                    (operand, '*', rng.gen())
+                }
+            };
+            let operand = monkeys.constant(rng, operand, budget);
+            let (left, right) = if humn_on_left {
+                (side, operand)
+            } else {
+                (operand, side)
+            };
+            side = monkeys.operation(rng, &left, operator, &right);
+        }
+        let other_side = monkeys.constant(rng, value, subtree_ops);
+        let (left, right) = if rng.gen() {
+            (side, other_side)
+        } else {
+            (other_side, side)
+        };
+        monkeys.lines.push(format!("root: {} + {}", left, right));
+        monkeys.lines.shuffle(rng);
+        join_lines(monkeys.lines)
+    }
+}
+
+/// Collects the jobs of generated monkeys, giving each monkey a new name.
+#[derive(Default)]
+struct MonkeyWriter {
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl MonkeyWriter {
+    fn new_name(&mut self, rng: &mut StdRng) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn yell(&mut self, name: &str, value: MonkeyValue) {
+        self.lines.push(format!("{}: {}", name, value));
+    }
+
+    fn operation(&mut self, rng: &mut StdRng, left: &str, operator: char, right: &str) -> String {
+        let name = self.new_name(rng);
+        self.lines
+            .push(format!("{}: {} {} {}", name, left, operator, right));
+        return name;
+    }
+
+    /// Adds monkeys that work out `value` using up to `budget` operations, without ever yelling a
+    /// negative number themselves, returning the name of the monkey at the top.
+    fn constant(&mut self, rng: &mut StdRng, value: MonkeyValue, budget: usize) -> String {
+        if budget == 0 && value >= 0 {
+            let name = self.new_name(rng);
+            self.yell(&name, value);
+            return name;
+        }
+        let divisors = (2..=9)
+            .filter(|&d| value >= d && value % d == 0)
+            .collect::<Vec<MonkeyValue>>();
+        let (left, operator, right) = match rng.gen_range(0..4) {
+            0 if value >= 2 => {
+                let left = rng.gen_range(1..value);
+                (left, '+', value - left)
+            }
+            1 if !divisors.is_empty() => {
+                let right = *divisors.choose(rng).unwrap();
+                (value / right, '*', right)
+            }
+            2 if value >= 0 => {
+                let right = rng.gen_range(2..=5);
+                (value * right, '/', right)
+            }
+            _ => {
+                let right = (-value).max(0) + rng.gen_range(1..=100);
+                (value + right, '-', right)
+            }
+        };
+        let budget = budget.saturating_sub(1);
+        let left_budget = rng.gen_range(0..=budget);
+        let left = self.constant(rng, left, left_budget);
+        let right = self.constant(rng, right, budget - left_budget);
+        return self.operation(rng, &left, operator, &right);
+    }
+}
+
+fn resolve_monkey_value(monkey_business: &MonkeyBusiness, monkey_name: &str) -> MonkeyValue {
+    match monkey_business.get(monkey_name) {
+        Some(MonkeyAction::YellValue(val)) => *val,
+        Some(MonkeyAction::DoOperation(operation)) => {
+            let operand_1 = resolve_monkey_value(monkey_business, operation.operand_1);
+            let operand_2 = resolve_monkey_value(monkey_business, operation.operand_2);
+            match operation.operator {
+                ArithmeticOperator::Add => operand_1 + operand_2,
+                ArithmeticOperator::Subtract => operand_1 - operand_2,
+                ArithmeticOperator::Multiply => operand_1 * operand_2,
+                ArithmeticOperator::Divide => operand_1 / operand_2,
+            }
+        }
+        None => panic!("Unknown monkey"),
+    }
+}
+
+fn resolve_monkey_expression(
+    monkey_business: &MonkeyBusiness,
+    monkey_name: &str,
+) -> ArithmeticExpression {
+    if monkey_name == "humn" {
+        return ArithmeticExpression::FreeVariable;
+    }
+    match monkey_business.get(monkey_name) {
+        Some(MonkeyAction::YellValue(val)) => ArithmeticExpression::Value(*val),
+        Some(MonkeyAction::DoOperation(operation)) => {
+            let operand_1 = resolve_monkey_expression(monkey_business, operation.operand_1);
+            let operand_2 = resolve_monkey_expression(monkey_business, operation.operand_2);
+            let operation = ArithmeticOperation {
+                operator: operation.operator,
+                operand_1,
+                operand_2,
+            };
+            ArithmeticExpression::Operation(Box::new(operation))
+        }
+        None => panic!("Unknown monkey"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day21>(EXAMPLE, Part::One), Ok(Answer::Int(152)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day21>(EXAMPLE, Part::Two), Ok(Answer::Int(301)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(
+            solve::<Day21>(INPUT, Part::One),
+            Ok(Answer::Int(160274622817992))
+        );
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(
+            solve::<Day21>(INPUT, Part::Two),
+            Ok(Answer::Int(3087390115721))
+        );
+    }
+
+    /// Evaluates a monkey straight from the input text, with `humn` yelling the given number. Returns
+    /// `None` if `exact` is set and a division leaves a remainder.
+    fn brute_force_yell(
+        jobs: &HashMap<&str, &str>,
+        name: &str,
+        humn: i128,
+        exact: bool,
+    ) -> Option<i128> {
+        if name == "humn" {
+            return Some(humn);
+        }
+        let job = jobs[name].split(' ').collect::<Vec<&str>>();
+        let [left, operator, right] = job[..] else {
+            return job[0].parse().ok();
+        };
+        let (left, right) = (
+            brute_force_yell(jobs, left, humn, exact)?,
+            brute_force_yell(jobs, right, humn, exact)?,
+        );
+        match operator {
+            "+" => Some(left + right),
+            "-" => Some(left - right),
+            "*" => Some(left * right),
+            _ => (!exact || left % right == 0).then(|| left / right),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day21>(seed, size);
+            let jobs = input.lines().map(|line| line.split_once(": ").unwrap()).collect::<HashMap<&str, &str>>();
+            let humn = jobs["humn"].parse().unwrap();
+            let part1 = brute_force_yell(&jobs, "root", humn, false).unwrap();
+            prop_assert_eq!(solve::<Day21>(&input, Part::One), Ok(Answer::from(part1)));
+
+            let Ok(Answer::Int(part2)) = solve::<Day21>(&input, Part::Two) else {
+                panic!("part 2 should be a number");
+            };
+            let root = jobs["root"].split(' ').collect::<Vec<&str>>();
+            let left = brute_force_yell(&jobs, root[0], part2 as i128, true);
+            prop_assert!(left.is_some());
+            prop_assert_eq!(left, brute_force_yell(&jobs, root[2], part2 as i128, true));
+        }
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-22", day_22::Day22);
@@ -0,0 +1,529 @@
+use common::{
+    answer::Answer,
+    direction::CardinalDirection,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::{blocks, char_grid, lines},
+    parse::{parse_num, ParseError},
+    pos::{MapPos, MapPosComp},
+    solution::{Part, Solution},
+    visualize::{Frame, Observer},
+};
+use std::collections::HashMap;
+
+type MonkeyMap = HashMap<(MapPosComp, MapPosComp), MapSpace>;
+
+#[derive(Clone)]
+struct MapSpace {
+    neighbors: HashMap<CardinalDirection, (MapPos, CardinalDirection)>,
+    is_wall: bool,
+}
+
+struct Player<'a> {
+    map: &'a MonkeyMap,
+    position: MapPos,
+    facing: CardinalDirection,
+    /// The way the player last faced on each space they've been on.
+    trail: HashMap<MapPos, CardinalDirection>,
+}
+
+impl Player<'_> {
+    fn new(map: &MonkeyMap) -> Player<'_> {
+        let position = *get_starting_map_position(map);
+        let facing = CardinalDirection::East;
+        Player {
+            map,
+            position,
+            facing,
+            trail: HashMap::from([(position, facing)]),
+        }
+    }
+
+    fn perform_actions(&mut self, actions: &Vec<PlayerAction>, observer: &mut dyn Observer) {
+        for action in actions {
+            match action {
+                PlayerAction::MoveForward(num) => self.move_forward(*num),
+                PlayerAction::TurnLeft => self.turn_left(),
+                PlayerAction::TurnRight => self.turn_right(),
+            }
+            self.trail.insert(self.position, self.facing);
+            observer.step(&|| self.draw());
+        }
+    }
+
+    /// Draws the map with walls as `#` and open tiles as `.`, the player's trail as arrows showing
+    /// which way they faced, and the player as `@`.
+    fn draw(&self) -> Frame {
+        let spaces = self.map.iter().map(|(&pos, space)| {
+            let tile = if space.is_wall { '#' } else { '.' };
+            (pos, tile)
+        });
+        let trail = self.trail.iter().map(|(&pos, facing)| {
+            let tile = match facing {
+                CardinalDirection::North => '^',
+                CardinalDirection::East => '>',
+                CardinalDirection::South => 'v',
+                CardinalDirection::West => '<',
+            };
+            (pos, tile)
+        });
+        let player = [(self.position, '@')];
+        Frame::from_points(spaces.chain(trail).chain(player), ' ')
+    }
+
+    fn get_current_map_space(&self) -> &MapSpace {
+        self.map.get(&self.position).unwrap()
+    }
+
+    fn get_next_destination_map_space(&self) -> &MapSpace {
+        self.map
+            .get(&self.get_next_destination_map_position().0)
+            .unwrap()
+    }
+
+    fn get_next_destination_map_position(&self) -> (MapPos, CardinalDirection) {
+        *self
+            .get_current_map_space()
+            .neighbors
+            .get(&self.facing)
+            .unwrap()
+    }
+
+    fn get_facing_num(&self) -> u8 {
+        match self.facing {
+            CardinalDirection::East => 0,
+            CardinalDirection::South => 1,
+            CardinalDirection::West => 2,
+            CardinalDirection::North => 3,
+        }
+    }
+
+    fn move_forward(&mut self, distance: usize) {
+        for _ in 0..distance {
+            let next_map_space = self.get_next_destination_map_space();
+            if next_map_space.is_wall {
+                break;
+            }
+            let destination_pos = self.get_next_destination_map_position();
+            self.trail.insert(self.position, self.facing);
+            self.position = destination_pos.0;
+            self.facing = destination_pos.1;
+        }
+    }
+
+    fn turn_left(&mut self) {
+        self.facing = self.facing.turn_left();
+    }
+
+    fn turn_right(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+}
+
+pub enum PlayerAction {
+    MoveForward(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+pub struct MonkeyNotes<'a> {
+    map_rows: Vec<&'a str>,
+    actions: Vec<PlayerAction>,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = MonkeyNotes<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut sections = blocks(input);
+        let map_string = sections.next().unwrap_or(input);
+        let action_string = sections.next().ok_or_else(|| {
+            ParseError::missing(
+                input,
+                map_string,
+                "a blank line between the map and the path",
+            )
+        })?;
+        if let Some(extra) = sections.next() {
+            return Err(ParseError::new(
+                input,
+                extra,
+                "expected only a map and a path",
+            ));
+        }
+        if let Some((_, _, tile)) = char_grid(map_string).find(|(_, c, _)| !" .#".contains(*c)) {
+            return Err(ParseError::new(
+                input,
+                tile,
+                "expected a tile: space, . or #",
+            ));
+        }
+        Ok(MonkeyNotes {
+            map_rows: lines(map_string).collect(),
+            actions: parse_actions(input, action_string.trim_end())?,
+        })
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> Answer {
+        Self::watch(notes, Part::One, &mut ())
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> Answer {
+        Self::watch(notes, Part::Two, &mut ())
+    }
+
+    fn watch(notes: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        match part {
+            Part::One => follow_path_on_flat_map(notes, observer).into(),
+            Part::Two => follow_path_on_cube(notes, observer).into(),
+        }
+    }
+}
+
+impl Generator for Day22 {
+    /// Makes a map with the same cube net as the real input, since part 2 only knows how that one
+    /// folds, with walls scattered over it, and a path of random turns and distances.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let side = (size / 10).clamp(2, 50);
+        // Which faces each band of rows has, counting across in steps of one face.
+        let net = [
+            [false, true, true],
+            [false, true, false],
+            [true, true, false],
+            [true, false, false],
+        ];
+        let mut map = Vec::new();
+        for faces in net {
+            let columns = faces.iter().rposition(|&face| face).unwrap() + 1;
+            for _ in 0..side {
+                let row = (0..columns * side)
+                    .map(|x| match faces[x / side] {
+                        false => ' ',
+                        true if rng.gen_ratio(1, 10) => '#',
+                        true => '.',
+                    })
+                    .collect::<String>();
+                map.push(row);
+            }
+        }
+        map[0].replace_range(side..side + 1, ".");
+        let mut path = rng.gen_range(1..=50).to_string();
+        for _ in 1..size.max(1) {
+            path.push(if rng.gen() { 'L' } else { 'R' });
+            path.push_str(&rng.gen_range(1..=50).to_string());
+        }
+        format!("{}\n\n{}", join_lines(map), path)
+    }
+}
+
+/// Follows the path with the map wrapping around from each edge to the opposite one.
+fn follow_path_on_flat_map(notes: &MonkeyNotes, observer: &mut dyn Observer) -> i64 {
+    let wrapping_monkey_map = parse_wrapping_monkey_map(&notes.map_rows);
+    let mut player = Player::new(&wrapping_monkey_map);
+    player.perform_actions(&notes.actions, observer);
+    calculate_password(&player)
+}
+
+/// Follows the path with the map folded up into a cube.
+fn follow_path_on_cube(notes: &MonkeyNotes, observer: &mut dyn Observer) -> i64 {
+    // Not smart enough to generalize this
+    let input_cube_side_transition_map = [
+        vec![
+            (CardinalDirection::North, (5, CardinalDirection::East)),
+            (CardinalDirection::East, (1, CardinalDirection::East)),
+            (CardinalDirection::South, (2, CardinalDirection::South)),
+            (CardinalDirection::West, (3, CardinalDirection::East)),
+        ],
+        vec![
+            (CardinalDirection::North, (5, CardinalDirection::North)),
+            (CardinalDirection::East, (4, CardinalDirection::West)),
+            (CardinalDirection::South, (2, CardinalDirection::West)),
+            (CardinalDirection::West, (0, CardinalDirection::West)),
+        ],
+        vec![
+            (CardinalDirection::North, (0, CardinalDirection::North)),
+            (CardinalDirection::East, (1, CardinalDirection::North)),
+            (CardinalDirection::South, (4, CardinalDirection::South)),
+            (CardinalDirection::West, (3, CardinalDirection::South)),
+        ],
+        vec![
+            (CardinalDirection::North, (2, CardinalDirection::East)),
+            (CardinalDirection::East, (4, CardinalDirection::East)),
+            (CardinalDirection::South, (5, CardinalDirection::South)),
+            (CardinalDirection::West, (0, CardinalDirection::East)),
+        ],
+        vec![
+            (CardinalDirection::North, (2, CardinalDirection::North)),
+            (CardinalDirection::East, (1, CardinalDirection::West)),
+            (CardinalDirection::South, (5, CardinalDirection::West)),
+            (CardinalDirection::West, (3, CardinalDirection::West)),
+        ],
+        vec![
+            (CardinalDirection::North, (3, CardinalDirection::North)),
+            (CardinalDirection::East, (4, CardinalDirection::North)),
+            (CardinalDirection::South, (1, CardinalDirection::South)),
+            (CardinalDirection::West, (0, CardinalDirection::South)),
+        ],
+    ]
+    .iter()
+    .map(|v| {
+        v.iter()
+            .cloned()
+            .collect::<HashMap<CardinalDirection, (usize, CardinalDirection)>>()
+    })
+    .collect::<Vec<HashMap<CardinalDirection, (usize, CardinalDirection)>>>();
+    let tile_count = notes
+        .map_rows
+        .iter()
+        .flat_map(|row| row.chars())
+        .filter(|c| *c == '.' || *c == '#')
+        .count();
+    let cube_side_length = ((tile_count / 6) as f64).sqrt() as usize;
+    let cubic_monkey_map = parse_cubic_monkey_map(
+        &notes.map_rows,
+        cube_side_length,
+        input_cube_side_transition_map,
+    );
+    let mut player = Player::new(&cubic_monkey_map);
+    player.perform_actions(&notes.actions, observer);
+    calculate_password(&player)
+}
+
+fn parse_wrapping_monkey_map(map_rows: &[&str]) -> MonkeyMap {
+    let flat_monkey_map = map_rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .collect::<Vec<(usize, char)>>()
+                .iter()
+                .filter(|(_, c)| *c != ' ')
+                .map(|(x, c)| {
+                    let is_wall = *c == '#';
+                    ((*x as MapPosComp, y as MapPosComp), is_wall)
+                })
+                .collect::<Vec<(MapPos, bool)>>()
+        })
+        .collect::<HashMap<MapPos, bool>>();
+    let monkey_map = flat_monkey_map
+        .iter()
+        .map(|(map_position, is_wall)| {
+            let mut neighbors = HashMap::new();
+            for direction in CardinalDirection::ALL.iter() {
+                let (x_offset, y_offset) = direction.get_offset();
+                let new_position = (
+                    map_position.0 + x_offset as MapPosComp,
+                    map_position.1 + y_offset as MapPosComp,
+                );
+                if flat_monkey_map.contains_key(&new_position) {
+                    neighbors.insert(*direction, (new_position, *direction));
+                    continue;
+                }
+                let mut opposite_boundary_position = *map_position;
+                let mut next_opposite_boundary_position = opposite_boundary_position;
+                while flat_monkey_map.contains_key(&next_opposite_boundary_position) {
+                    opposite_boundary_position = next_opposite_boundary_position;
+                    next_opposite_boundary_position = (
+                        opposite_boundary_position.0 - x_offset as MapPosComp,
+                        opposite_boundary_position.1 - y_offset as MapPosComp,
+                    );
+                }
+                neighbors.insert(*direction, (opposite_boundary_position, *direction));
+            }
+            (
+                *map_position,
+                MapSpace {
+                    neighbors,
+                    is_wall: *is_wall,
+                },
+            )
+        })
+        .collect::<MonkeyMap>();
+    return monkey_map;
+}
+
+fn parse_cubic_monkey_map(
+    map_rows: &[&str],
+    side_length: usize,
+    transition_table: Vec<HashMap<CardinalDirection, (usize, CardinalDirection)>>,
+) -> MonkeyMap {
+    let mut current_face = 0;
+    let mut cubic_faces = vec![vec![vec![]; side_length]; 6];
+    for (i, row_chunk) in map_rows.chunks(side_length).enumerate() {
+        for j in 0..(row_chunk[0].len() / side_length) {
+            let mut increase_face = false;
+            for (ri, row) in row_chunk.iter().enumerate() {
+                let row_slice = &row[side_length * j..side_length * (j + 1)];
+                if row_slice.chars().into_iter().any(|c| c != ' ') {
+                    increase_face = true;
+                }
+                if increase_face {
+                    for (ci, c) in row_slice.chars().enumerate() {
+                        let is_wall = c == '#';
+                        let x = j * side_length + ci;
+                        let y = i * side_length + ri;
+                        cubic_faces[current_face][ri].push(((x as i64, y as i64), is_wall));
+                    }
+                }
+            }
+            if increase_face {
+                current_face += 1;
+            }
+        }
+    }
+    let mut monkey_map = HashMap::new();
+    for (fi, face) in cubic_faces.iter().enumerate() {
+        for (y, row) in face.iter().enumerate() {
+            for (x, pos) in row.iter().enumerate() {
+                let mut neighbors = HashMap::new();
+                for direction in CardinalDirection::ALL.iter() {
+                    let (x_offset, y_offset) = direction.get_offset();
+                    let new_position_within_face =
+                        (x as i32 + x_offset as i32, y as i32 + y_offset as i32);
+                    if new_position_within_face.0 < 0
+                        || new_position_within_face.0 >= side_length as i32
+                        || new_position_within_face.1 < 0
+                        || new_position_within_face.1 >= side_length as i32
+                    {
+                        let (new_face, new_direction) = transition_table[fi][direction];
+                        let other_face_pos = match (direction, new_direction) {
+                            (CardinalDirection::North, CardinalDirection::North) => {
+                                (x, side_length - 1)
+                            }
+                            (CardinalDirection::North, CardinalDirection::South) => {
+                                (side_length - x - 1, 0)
+                            }
+                            (CardinalDirection::North, CardinalDirection::West) => {
+                                (side_length - 1, side_length - x - 1)
+                            }
+                            (CardinalDirection::North, CardinalDirection::East) => (0, x),
+                            (CardinalDirection::South, CardinalDirection::North) => {
+                                (side_length - x - 1, side_length - 1)
+                            }
+                            (CardinalDirection::South, CardinalDirection::South) => (x, 0),
+                            (CardinalDirection::South, CardinalDirection::West) => {
+                                (side_length - 1, x)
+                            }
+                            (CardinalDirection::South, CardinalDirection::East) => {
+                                (0, side_length - x - 1)
+                            }
+                            (CardinalDirection::West, CardinalDirection::North) => {
+                                (side_length - y - 1, side_length - 1)
+                            }
+                            (CardinalDirection::West, CardinalDirection::South) => (y, 0),
+                            (CardinalDirection::West, CardinalDirection::West) => {
+                                (side_length - 1, y)
+                            }
+                            (CardinalDirection::West, CardinalDirection::East) => {
+                                (0, side_length - y - 1)
+                            }
+                            (CardinalDirection::East, CardinalDirection::North) => {
+                                (y, side_length - 1)
+                            }
+                            (CardinalDirection::East, CardinalDirection::South) => {
+                                (side_length - y - 1, 0)
+                            }
+                            (CardinalDirection::East, CardinalDirection::West) => {
+                                (side_length - 1, side_length - y - 1)
+                            }
+                            (CardinalDirection::East, CardinalDirection::East) => (0, y),
+                        };
+                        let new_pos = cubic_faces[new_face][other_face_pos.1][other_face_pos.0];
+                        neighbors.insert(*direction, (new_pos.0, new_direction));
+                        continue;
+                    }
+                    let new_pos = cubic_faces[fi][new_position_within_face.1 as usize]
+                        [new_position_within_face.0 as usize];
+                    neighbors.insert(*direction, (new_pos.0, *direction));
+                }
+                monkey_map.insert(
+                    pos.0,
+                    MapSpace {
+                        neighbors,
+                        is_wall: pos.1,
+                    },
+                );
+            }
+        }
+    }
+    return monkey_map;
+}
+
+fn parse_actions(input: &str, action_char_sequence: &str) -> Result<Vec<PlayerAction>, ParseError> {
+    let mut actions = vec![];
+    let mut num_start = 0;
+    for (i, c) in action_char_sequence.char_indices() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+        let action = match c {
+            'L' => PlayerAction::TurnLeft,
+            'R' => PlayerAction::TurnRight,
+            _ => {
+                return Err(ParseError::new(
+                    input,
+                    &action_char_sequence[i..i + c.len_utf8()],
+                    "expected a distance, L or R",
+                ))
+            }
+        };
+        let num = parse_num(input, &action_char_sequence[num_start..i])?;
+        actions.push(PlayerAction::MoveForward(num));
+        actions.push(action);
+        num_start = i + 1;
+    }
+    if num_start < action_char_sequence.len() {
+        let num = parse_num(input, &action_char_sequence[num_start..])?;
+        actions.push(PlayerAction::MoveForward(num));
+    }
+    return Ok(actions);
+}
+
+fn get_starting_map_position(monkey_map: &MonkeyMap) -> &MapPos {
+    let mut starting_map_positions = monkey_map
+        .iter()
+        .filter(|(map_pos, map_space)| map_pos.1 == 0 && !map_space.is_wall)
+        .collect::<Vec<(&MapPos, &MapSpace)>>();
+    starting_map_positions.sort_by_key(|(map_pos_a, _)| map_pos_a.0);
+    let starting_map_position = starting_map_positions.first().unwrap().0;
+    return starting_map_position;
+}
+
+fn calculate_password(player: &Player) -> i64 {
+    let (row, col, facing) = (
+        player.position.1 + 1,
+        player.position.0 + 1,
+        player.get_facing_num(),
+    );
+    let password = row * 1000 + col * 4 + facing as i64;
+    return password;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    // Part 2 folds the cube using a transition map written for the real input's net, which
+    // the example's net doesn't share.
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day22>(EXAMPLE, Part::One), Ok(Answer::Int(6032)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day22>(INPUT, Part::One), Ok(Answer::Int(159034)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day22>(INPUT, Part::Two), Ok(Answer::Int(147245)));
+    }
+}
@@ -0,0 +1 @@
+common::bench_day!("day-23", day_23::Day23);
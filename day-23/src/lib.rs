@@ -0,0 +1,273 @@
+use common::{
+    answer::Answer,
+    direction::{CanAddCardinalDirection, CardinalDirection},
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::char_grid,
+    parse::ParseError,
+    pos::MapPos,
+    solution::{Part, Solution},
+    visualize::{Frame, Observer},
+};
+use std::collections::{HashMap, HashSet};
+
+type ElfPos = MapPos;
+
+type ElfGroup = HashSet<ElfPos>;
+
+struct ElfGroupDispersalGame {
+    group: ElfGroup,
+    direction_order: Vec<CardinalDirection>,
+    round_number: u64,
+    elves_moved_last_round: bool,
+}
+
+impl ElfGroupDispersalGame {
+    fn new(group: ElfGroup) -> Self {
+        let direction_order = vec![
+            CardinalDirection::North,
+            CardinalDirection::South,
+            CardinalDirection::West,
+            CardinalDirection::East,
+        ];
+        ElfGroupDispersalGame {
+            group,
+            direction_order,
+            round_number: 0,
+            elves_moved_last_round: true,
+        }
+    }
+
+    fn perform_rounds(&mut self, n: usize, observer: &mut dyn Observer) {
+        for _ in 0..n {
+            self.perform_round();
+            observer.step(&|| self.draw());
+        }
+    }
+
+    fn perform_rounds_until_stable(&mut self, observer: &mut dyn Observer) {
+        while self.elves_moved_last_round {
+            self.perform_round();
+            observer.step(&|| self.draw());
+        }
+    }
+
+    /// Draws the elves as `#` on the smallest rectangle that holds them all.
+    fn draw(&self) -> Frame {
+        Frame::from_points(self.group.iter().map(|&elf_pos| (elf_pos, '#')), '.')
+    }
+
+    fn perform_round(&mut self) {
+        let mut elves_moved_this_round = false;
+        let mut tentative_elf_destinations = HashMap::new();
+        for elf_pos in self.group.iter() {
+            let destination = if self.elf_is_isolated(elf_pos) {
+                *elf_pos
+            } else {
+                elves_moved_this_round = true;
+                let mut destination = None;
+                for direction in self.direction_order.iter() {
+                    if self.elf_can_move_in_direction(elf_pos, direction) {
+                        destination = Some(elf_pos.add(*direction));
+                        break;
+                    }
+                }
+                destination.unwrap_or(*elf_pos)
+            };
+            tentative_elf_destinations
+                .entry(destination)
+                .or_insert(vec![])
+                .push(*elf_pos);
+        }
+        let mut new_group = ElfGroup::new();
+        for (destination_pos, elves_going_to_destination) in tentative_elf_destinations.iter() {
+            if elves_going_to_destination.len() == 1 {
+                new_group.insert(*destination_pos);
+            } else {
+                for &elf_pos in elves_going_to_destination {
+                    new_group.insert(elf_pos);
+                }
+            }
+        }
+        self.elves_moved_last_round = elves_moved_this_round;
+        self.group = new_group;
+        self.advance_to_next_round();
+    }
+
+    fn elf_is_isolated(&self, elf: &ElfPos) -> bool {
+        let (x, y) = *elf;
+        let north = (x, y - 1);
+        let north_west = (x - 1, y - 1);
+        let west = (x - 1, y);
+        let south_west = (x - 1, y + 1);
+        let south = (x, y + 1);
+        let south_east = (x + 1, y + 1);
+        let east = (x + 1, y);
+        let north_east = (x + 1, y - 1);
+        [
+            north, north_west, west, south_west, south, south_east, east, north_east,
+        ]
+        .iter()
+        .all(|pos| !self.group.contains(pos))
+    }
+
+    fn elf_can_move_in_direction(&self, elf: &ElfPos, direction: &CardinalDirection) -> bool {
+        let (x, y) = *elf;
+        match direction {
+            CardinalDirection::North => {
+                let north = (x, y - 1);
+                let north_west = (x - 1, y - 1);
+                let north_east = (x + 1, y - 1);
+                [north, north_west, north_east]
+                    .iter()
+                    .all(|pos| !self.group.contains(pos))
+            }
+            CardinalDirection::East => {
+                let east = (x + 1, y);
+                let north_east = (x + 1, y - 1);
+                let south_east = (x + 1, y + 1);
+                [east, north_east, south_east]
+                    .iter()
+                    .all(|pos| !self.group.contains(pos))
+            }
+            CardinalDirection::South => {
+                let south = (x, y + 1);
+                let south_west = (x - 1, y + 1);
+                let south_east = (x + 1, y + 1);
+                [south, south_west, south_east]
+                    .iter()
+                    .all(|pos| !self.group.contains(pos))
+            }
+            CardinalDirection::West => {
+                let west = (x - 1, y);
+                let north_west = (x - 1, y - 1);
+                let south_west = (x - 1, y + 1);
+                [west, north_west, south_west]
+                    .iter()
+                    .all(|pos| !self.group.contains(pos))
+            }
+        }
+    }
+
+    fn advance_to_next_round(&mut self) {
+        self.round_number += 1;
+        self.direction_order.rotate_left(1);
+    }
+
+    fn count_empty_spaces_within_bounds(&self) -> usize {
+        let ((min_x, min_y), (max_x, max_y)) = self.get_group_bounds();
+        let mut empty_spaces = 0;
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                if !self.group.contains(&(x, y)) {
+                    empty_spaces += 1;
+                }
+            }
+        }
+        empty_spaces
+    }
+
+    fn get_group_bounds(&self) -> (ElfPos, ElfPos) {
+        let mut min_x = i64::MAX;
+        let mut min_y = i64::MAX;
+        let mut max_x = i64::MIN;
+        let mut max_y = i64::MIN;
+        for (x, y) in self.group.iter() {
+            if *x < min_x {
+                min_x = *x;
+            }
+            if *y < min_y {
+                min_y = *y;
+            }
+            if *x > max_x {
+                max_x = *x;
+            }
+            if *y > max_y {
+                max_y = *y;
+            }
+        }
+        ((min_x, min_y), (max_x, max_y))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = ElfGroup;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut group = ElfGroup::new();
+        for (pos, c, token) in char_grid(input) {
+            match c {
+                '#' => {
+                    group.insert(pos);
+                }
+                '.' => {}
+                _ => return Err(ParseError::new(input, token, "expected . or #")),
+            }
+        }
+        Ok(group)
+    }
+
+    fn part1(group: &Self::Input<'_>) -> Answer {
+        Self::watch(group, Part::One, &mut ())
+    }
+
+    fn part2(group: &Self::Input<'_>) -> Answer {
+        Self::watch(group, Part::Two, &mut ())
+    }
+
+    fn watch(group: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        let mut game = ElfGroupDispersalGame::new(group.clone());
+        match part {
+            Part::One => {
+                let target_rounds = 10;
+                game.perform_rounds(target_rounds, observer);
+                game.count_empty_spaces_within_bounds().into()
+            }
+            Part::Two => {
+                game.perform_rounds_until_stable(observer);
+                game.round_number.into()
+            }
+        }
+    }
+}
+
+impl Generator for Day23 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(1);
+        join_lines((0..side).map(|_| {
+            (0..side)
+                .map(|_| if rng.gen() { '#' } else { '.' })
+                .collect()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{solve, Part};
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day23>(EXAMPLE, Part::One), Ok(Answer::Int(110)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day23>(EXAMPLE, Part::Two), Ok(Answer::Int(20)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day23>(INPUT, Part::One), Ok(Answer::Int(4045)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day23>(INPUT, Part::Two), Ok(Answer::Int(963)));
+    }
+}
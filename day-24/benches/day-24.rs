@@ -0,0 +1 @@
+common::bench_day!("day-24", day_24::Day24);
@@ -0,0 +1,413 @@
+use common::{
+    answer::Answer,
+    direction::CardinalDirection,
+    generate::{join_lines, Generator, Rng, SliceRandom, StdRng},
+    input::lines,
+    parse::ParseError,
+    pos::{MapPos, MapPosComp},
+    search::bfs,
+    solution::{Part, Solution},
+    visualize::{Frame, Observer},
+};
+use std::collections::{HashMap, HashSet};
+
+struct Blizzard {
+    initial_position: MapPos,
+    direction: CardinalDirection,
+}
+
+pub struct BlizzardMap {
+    blizzards: Vec<Blizzard>,
+    bounds: (MapPos, MapPos),
+}
+
+impl BlizzardMap {
+    fn get_blizzard_positions_after_steps(&self, steps: u16) -> HashSet<MapPos> {
+        self.blizzards
+            .iter()
+            .map(|blizzard| self.get_blizzard_position_after_steps(blizzard, steps))
+            .collect()
+    }
+
+    fn get_blizzard_position_after_steps(&self, blizzard: &Blizzard, steps: u16) -> MapPos {
+        let steps = steps as MapPosComp;
+        let width = self.get_map_width();
+        let height = self.get_map_height();
+        match blizzard.direction {
+            CardinalDirection::North => (
+                blizzard.initial_position.0,
+                (blizzard.initial_position.1 - steps).rem_euclid(height),
+            ),
+            CardinalDirection::South => (
+                blizzard.initial_position.0,
+                (blizzard.initial_position.1 + steps).rem_euclid(height),
+            ),
+            CardinalDirection::East => (
+                (blizzard.initial_position.0 + steps).rem_euclid(width),
+                blizzard.initial_position.1,
+            ),
+            CardinalDirection::West => (
+                (blizzard.initial_position.0 - steps).rem_euclid(width),
+                blizzard.initial_position.1,
+            ),
+        }
+    }
+
+    /// Draws the valley as it is after `steps` steps, as in the puzzle: walls as `#`, blizzards as
+    /// arrows, or as how many there are where several meet, and the expedition as `E`.
+    fn draw(&self, steps: u16, expedition: MapPos) -> Frame {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds;
+        let mut frame = Frame::new(((min_x - 1, min_y - 1), (max_x + 1, max_y + 1)), '.');
+        for x in min_x - 1..=max_x + 1 {
+            frame.set((x, min_y - 1), '#');
+            frame.set((x, max_y + 1), '#');
+        }
+        for y in min_y..=max_y {
+            frame.set((min_x - 1, y), '#');
+            frame.set((max_x + 1, y), '#');
+        }
+        frame.set(self.get_start_pos(), '.');
+        frame.set(self.get_end_pos(), '.');
+        let mut blizzards_by_pos: HashMap<MapPos, Vec<&Blizzard>> = HashMap::new();
+        for blizzard in &self.blizzards {
+            let pos = self.get_blizzard_position_after_steps(blizzard, steps);
+            blizzards_by_pos.entry(pos).or_default().push(blizzard);
+        }
+        for (pos, blizzards) in blizzards_by_pos {
+            let tile = match blizzards[..] {
+                [blizzard] => match blizzard.direction {
+                    CardinalDirection::North => '^',
+                    CardinalDirection::South => 'v',
+                    CardinalDirection::East => '>',
+                    CardinalDirection::West => '<',
+                },
+                _ => char::from_digit(blizzards.len().min(9) as u32, 10).unwrap(),
+            };
+            frame.set(pos, tile);
+        }
+        frame.set(expedition, 'E');
+        return frame;
+    }
+
+    fn get_start_pos(&self) -> MapPos {
+        (self.bounds.0 .0, self.bounds.0 .1 - 1)
+    }
+
+    fn get_end_pos(&self) -> MapPos {
+        (self.bounds.1 .0, self.bounds.1 .1 + 1)
+    }
+
+    fn is_in_valley(&self, pos: MapPos) -> bool {
+        (self.bounds.0 .0..=self.bounds.1 .0).contains(&pos.0)
+            && (self.bounds.0 .1..=self.bounds.1 .1).contains(&pos.1)
+    }
+
+    fn get_map_width(&self) -> MapPosComp {
+        self.bounds.1 .0 - self.bounds.0 .0 + 1
+    }
+
+    fn get_map_height(&self) -> MapPosComp {
+        self.bounds.1 .1 - self.bounds.0 .1 + 1
+    }
+
+    /// How many steps it takes for the blizzards to all be back where they started.
+    fn get_period(&self) -> u16 {
+        let (width, height) = (self.get_map_width(), self.get_map_height());
+        let (mut a, mut b) = (width, height);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        (width / a * height) as u16
+    }
+}
+
+const PLAYER_MOVEMENT_DIRECTIONS: [MapPos; 5] = [(0, 0), (0, -1), (0, 1), (1, 0), (-1, 0)];
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = BlizzardMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines = lines(input).map(|line| line.trim()).collect::<Vec<&str>>();
+        if lines.len() < 3 || lines.iter().any(|line| line.chars().count() < 3) {
+            return Err(ParseError::new(
+                input,
+                input,
+                "expected a walled valley at least one space across",
+            ));
+        }
+        let mut blizzards = Vec::new();
+        let width = lines[0].chars().count() - 2;
+        for (y, line) in lines[1..lines.len() - 1].iter().enumerate() {
+            let interior = line
+                .strip_prefix('#')
+                .and_then(|line| line.strip_suffix('#'))
+                .ok_or_else(|| ParseError::new(input, line, "expected a wall # at each end"))?;
+            if interior.chars().count() != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("expected the valley to be {} spaces across", width),
+                ));
+            }
+            for (x, (i, c)) in interior.char_indices().enumerate() {
+                let direction = match c {
+                    '.' => continue,
+                    '^' => CardinalDirection::North,
+                    'v' => CardinalDirection::South,
+                    '>' => CardinalDirection::East,
+                    '<' => CardinalDirection::West,
+                    _ => {
+                        return Err(ParseError::new(
+                            input,
+                            &interior[i..i + c.len_utf8()],
+                            "expected a blizzard: ^, v, > or <",
+                        ))
+                    }
+                };
+                blizzards.push(Blizzard {
+                    initial_position: (x as MapPosComp, y as MapPosComp),
+                    direction,
+                });
+            }
+        }
+        Ok(BlizzardMap {
+            blizzards,
+            bounds: (
+                (0, 0),
+                (width as MapPosComp - 1, lines.len() as MapPosComp - 3),
+            ),
+        })
+    }
+
+    fn part1(blizzard_map: &Self::Input<'_>) -> Answer {
+        Self::watch(blizzard_map, Part::One, &mut ())
+    }
+
+    fn part2(blizzard_map: &Self::Input<'_>) -> Answer {
+        Self::watch(blizzard_map, Part::Two, &mut ())
+    }
+
+    /// The observer sees each step of the quickest route once it has been found.
+    fn watch(blizzard_map: &Self::Input<'_>, part: Part, observer: &mut dyn Observer) -> Answer {
+        let start_pos = blizzard_map.get_start_pos();
+        let end_pos = blizzard_map.get_end_pos();
+        let steps_to_end =
+            get_shortest_distance_of_path(blizzard_map, start_pos, end_pos, 0, observer);
+        if part == Part::One {
+            return steps_to_end.into();
+        }
+        let steps_from_end_to_start =
+            get_shortest_distance_of_path(blizzard_map, end_pos, start_pos, steps_to_end, observer);
+        let steps_round_trip = steps_to_end + steps_from_end_to_start;
+        let steps_from_start_to_end_trip_2 = get_shortest_distance_of_path(
+            blizzard_map,
+            start_pos,
+            end_pos,
+            steps_round_trip,
+            observer,
+        );
+        let steps_total = steps_to_end + steps_from_end_to_start + steps_from_start_to_end_trip_2;
+        steps_total.into()
+    }
+}
+
+impl Generator for Day24 {
+    /// Makes a valley a third full of blizzards, with none blowing up or down the columns of the
+    /// entrance and the exit, like in the real input. Valleys where the blizzards cut off one end
+    /// from the other are thrown away.
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let width = size.max(2);
+        let height = (size / 4).max(1);
+        loop {
+            let input = generate_valley(rng, width, height);
+            let map = Day24::parse(&input).unwrap();
+            let (start, end) = (map.get_start_pos(), map.get_end_pos());
+            let mut step = 0;
+            let passable =
+                [(start, end), (end, start), (start, end)]
+                    .into_iter()
+                    .all(
+                        |(from, to)| match find_quickest_route(&map, from, to, step) {
+                            Some(path) => {
+                                step += path.len() as u16 - 1;
+                                true
+                            }
+                            None => false,
+                        },
+                    );
+            if passable {
+                return input;
+            }
+        }
+    }
+}
+
+fn generate_valley(rng: &mut StdRng, width: usize, height: usize) -> String {
+    let mut valley = vec![format!("#.{}", "#".repeat(width))];
+    for _ in 0..height {
+        let row = (0..width)
+            .map(|x| {
+                let blizzards: &[char] = if x == 0 || x == width - 1 {
+                    &['<', '>']
+                } else {
+                    &['<', '>', '^', 'v']
+                };
+                match rng.gen_ratio(1, 3) {
+                    true => *blizzards.choose(rng).unwrap(),
+                    false => '.',
+                }
+            })
+            .collect::<String>();
+        valley.push(format!("#{}#", row));
+    }
+    valley.push(format!("{}.#", "#".repeat(width)));
+    join_lines(valley)
+}
+
+fn get_shortest_distance_of_path(
+    blizzard_map: &BlizzardMap,
+    start_pos: MapPos,
+    end_pos: MapPos,
+    starting_step: u16,
+    observer: &mut dyn Observer,
+) -> u16 {
+    let path = find_quickest_route(blizzard_map, start_pos, end_pos, starting_step)
+        .unwrap_or_else(|| panic!("No path found from {:?} to {:?}", start_pos, end_pos));
+    for &(pos, step) in &path[1..] {
+        observer.step(&|| blizzard_map.draw(step, pos));
+    }
+    return (path.len() - 1) as u16;
+}
+
+/// Finds the quickest route between two positions, as the position after each step along with the
+/// step counted from the start of the first trip. The blizzards repeat their pattern every
+/// `get_period()` steps, so steps are counted modulo that, which lets the search give up once it has
+/// been everywhere it can be at every point in the pattern.
+fn find_quickest_route(
+    blizzard_map: &BlizzardMap,
+    start_pos: MapPos,
+    end_pos: MapPos,
+    starting_step: u16,
+) -> Option<Vec<(MapPos, u16)>> {
+    let period = blizzard_map.get_period();
+    let mut blizzards_by_step = HashMap::new();
+    let search = bfs(
+        [(start_pos, starting_step % period)],
+        |&(pos, step)| {
+            let next_step = (step + 1) % period;
+            let blizzards = blizzards_by_step
+                .entry(next_step)
+                .or_insert_with(|| blizzard_map.get_blizzard_positions_after_steps(next_step));
+            PLAYER_MOVEMENT_DIRECTIONS
+                .iter()
+                .map(|direction| (pos.0 + direction.0, pos.1 + direction.1))
+                .filter(|&new_pos| {
+                    new_pos == start_pos || new_pos == end_pos || blizzard_map.is_in_valley(new_pos)
+                })
+                .filter(|new_pos| !blizzards.contains(new_pos))
+                .map(|new_pos| (new_pos, next_step))
+                .collect::<Vec<(MapPos, u16)>>()
+        },
+        |&(pos, _)| pos == end_pos,
+    );
+    search.goal_path()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day24>(EXAMPLE, Part::One), Ok(Answer::Int(18)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day24>(EXAMPLE, Part::Two), Ok(Answer::Int(54)));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(solve::<Day24>(INPUT, Part::One), Ok(Answer::Int(249)));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(solve::<Day24>(INPUT, Part::Two), Ok(Answer::Int(735)));
+    }
+
+    #[test]
+    fn rows_without_walls_are_errors() {
+        assert!(Day24::parse("#.###\n#>..#\nݽ..\n###.#").is_err());
+        assert!(Day24::parse("#.###\n#>..#\n#...#.\n###.#").is_err());
+    }
+
+    /// Moves every blizzard one square a minute and tracks every square the expedition could be in,
+    /// for the trip there, back and there again.
+    fn brute_force_trips(input: &str) -> (usize, usize) {
+        let rows = input
+            .lines()
+            .map(|line| line.as_bytes())
+            .collect::<Vec<&[u8]>>();
+        let (width, height) = (rows[0].len() as i32 - 2, rows.len() as i32 - 2);
+        let mut blizzards = Vec::new();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let direction = match c {
+                    b'^' => (0, -1),
+                    b'v' => (0, 1),
+                    b'<' => (-1, 0),
+                    b'>' => (1, 0),
+                    _ => continue,
+                };
+                blizzards.push(((x as i32 - 1, y as i32 - 1), direction));
+            }
+        }
+        let (start, end) = ((0, -1), (width - 1, height));
+        let mut minutes = Vec::new();
+        let mut minute = 0;
+        for (from, to) in [(start, end), (end, start), (start, end)] {
+            let mut frontier = HashSet::from([from]);
+            while !frontier.contains(&to) {
+                minute += 1;
+                for ((x, y), (dx, dy)) in blizzards.iter_mut() {
+                    (*x, *y) = ((*x + *dx).rem_euclid(width), (*y + *dy).rem_euclid(height));
+                }
+                let blocked = blizzards
+                    .iter()
+                    .map(|&(pos, _)| pos)
+                    .collect::<HashSet<(i32, i32)>>();
+                frontier = frontier
+                    .iter()
+                    .flat_map(|&(x, y)| [(x, y), (x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)])
+                    .filter(|&(x, y)| {
+                        let in_valley = (0..width).contains(&x) && (0..height).contains(&y);
+                        (in_valley || (x, y) == start || (x, y) == end)
+                            && !blocked.contains(&(x, y))
+                    })
+                    .collect();
+            }
+            minutes.push(minute);
+        }
+        (minutes[0], minutes[2])
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..30usize) {
+            let input = generate::<Day24>(seed, size);
+            let (part1, part2) = brute_force_trips(&input);
+            prop_assert_eq!(solve::<Day24>(&input, Part::One), Ok(Answer::from(part1)));
+            prop_assert_eq!(solve::<Day24>(&input, Part::Two), Ok(Answer::from(part2)));
+        }
+    }
+}
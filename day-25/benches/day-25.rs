@@ -0,0 +1,2 @@
+// Day 25 has no second part.
+common::bench_day!("day-25", day_25::Day25, [common::solution::Part::One]);
@@ -0,0 +1,245 @@
+use common::{
+    answer::Answer,
+    generate::{join_lines, Generator, Rng, StdRng},
+    input::{lines, StreamError, StreamLines},
+    parse::ParseError,
+    solution::{Part, Solution, StreamSolution},
+};
+use std::io::BufRead;
+
+pub enum SNAFUDigit {
+    Zero,
+    One,
+    Two,
+    Minus,
+    DoubleMinus,
+}
+
+impl SNAFUDigit {
+    fn from_char(c: char) -> Option<SNAFUDigit> {
+        match c {
+            '0' => Some(SNAFUDigit::Zero),
+            '1' => Some(SNAFUDigit::One),
+            '2' => Some(SNAFUDigit::Two),
+            '-' => Some(SNAFUDigit::Minus),
+            '=' => Some(SNAFUDigit::DoubleMinus),
+            _ => None,
+        }
+    }
+}
+
+type SNAFUNumber = Vec<SNAFUDigit>;
+
+trait ToString {
+    fn to_string(&self) -> String;
+}
+
+impl ToString for SNAFUNumber {
+    fn to_string(&self) -> String {
+        let mut result = String::new();
+        for digit in self.iter().rev() {
+            result.push(match digit {
+                SNAFUDigit::Zero => '0',
+                SNAFUDigit::One => '1',
+                SNAFUDigit::Two => '2',
+                SNAFUDigit::Minus => '-',
+                SNAFUDigit::DoubleMinus => '=',
+            });
+        }
+        result
+    }
+}
+
+trait Toi64 {
+    fn to_i64(&self) -> Option<i64>;
+}
+
+impl Toi64 for SNAFUNumber {
+    /// The number's value, or `None` if it's too big for an `i64`.
+    fn to_i64(&self) -> Option<i64> {
+        self.iter().rev().try_fold(0_i64, |result, digit| {
+            let value = match digit {
+                SNAFUDigit::Zero => 0,
+                SNAFUDigit::One => 1,
+                SNAFUDigit::Two => 2,
+                SNAFUDigit::Minus => -1,
+                SNAFUDigit::DoubleMinus => -2,
+            };
+            result.checked_mul(5)?.checked_add(value)
+        })
+    }
+}
+
+trait Fromi64 {
+    fn from_i64(num: i64) -> SNAFUNumber;
+}
+
+impl Fromi64 for SNAFUNumber {
+    fn from_i64(num: i64) -> SNAFUNumber {
+        let mut result = SNAFUNumber::new();
+        let mut remaining = num;
+        while remaining != 0 {
+            let (normal_div, rem) = (remaining / 5, remaining % 5);
+            let (div, cur) = match rem {
+                0 => (normal_div, SNAFUDigit::Zero),
+                1 => (normal_div, SNAFUDigit::One),
+                2 => (normal_div, SNAFUDigit::Two),
+                3 => (normal_div + 1, SNAFUDigit::DoubleMinus),
+                4 => (normal_div + 1, SNAFUDigit::Minus),
+                _ => panic!("Invalid SNAFU digit"),
+            };
+            result.push(cur);
+            remaining = div;
+        }
+        return result;
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<SNAFUNumber>;
+
+    /// Also checks that the numbers and their sum fit in an `i64`, so that they can be added up
+    /// without overflowing.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut sum = 0;
+        lines(input)
+            .map(|line| {
+                let number = parse_snafu(input, line)?;
+                sum = add_snafu(input, line, sum, &number)?;
+                Ok(number)
+            })
+            .collect()
+    }
+
+    fn part1(snafu_numbers: &Self::Input<'_>) -> Answer {
+        let sum = snafu_numbers
+            .iter()
+            .map(|num| num.to_i64().unwrap())
+            .sum::<i64>();
+        let sum_snafu = SNAFUNumber::from_i64(sum);
+        sum_snafu.to_string().into()
+    }
+}
+
+impl StreamSolution for Day25 {
+    fn solve_stream(reader: &mut dyn BufRead, part: Part) -> Result<Answer, StreamError> {
+        let mut sum = 0;
+        StreamLines::new(reader).try_for_each(|line| {
+            sum = add_snafu(line, line, sum, &parse_snafu(line, line)?)?;
+            Ok(())
+        })?;
+        match part {
+            Part::One => Ok(SNAFUNumber::from_i64(sum).to_string().into()),
+            Part::Two => Ok(Answer::None),
+        }
+    }
+}
+
+fn parse_snafu(input: &str, line: &str) -> Result<SNAFUNumber, ParseError> {
+    let line = line.trim();
+    line.char_indices()
+        .rev()
+        .map(|(i, c)| {
+            SNAFUDigit::from_char(c).ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    "expected a SNAFU digit: 2, 1, 0, - or =",
+                )
+            })
+        })
+        .collect()
+}
+
+/// Adds the number on `line` to the sum so far, reporting the line if either doesn't fit in an
+/// `i64`.
+fn add_snafu(input: &str, line: &str, sum: i64, number: &SNAFUNumber) -> Result<i64, ParseError> {
+    number
+        .to_i64()
+        .and_then(|number| sum.checked_add(number))
+        .ok_or_else(|| {
+            ParseError::new(
+                input,
+                line.trim(),
+                format!(
+                    "the numbers add up to more than fits between {} and {}",
+                    i64::MIN,
+                    i64::MAX
+                ),
+            )
+        })
+}
+
+impl Generator for Day25 {
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        join_lines((0..size.max(1)).map(|_| {
+            let largest = 10_i64.pow(rng.gen_range(1..=12));
+            SNAFUNumber::from_i64(rng.gen_range(1..=largest)).to_string()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::generate;
+    use common::solution::{solve, Part};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../resources/example.txt");
+    const INPUT: &str = include_str!("../resources/input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve::<Day25>(EXAMPLE, Part::One),
+            Ok(Answer::from("2=-1=0"))
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(
+            solve::<Day25>(INPUT, Part::One),
+            Ok(Answer::from("2---1010-0=1220-=010"))
+        );
+    }
+
+    #[test]
+    fn sums_too_big_for_an_i64_are_errors() {
+        // 1 followed by 27 zeros is 5^27, about 7.5e18, so two of them overflow.
+        let big = format!("1{}", "0".repeat(27));
+        let input = format!("{}\n-\n{}", big, big);
+        assert_eq!(Day25::parse(&input).err().unwrap().line, 3);
+        let streamed = Day25::solve_stream(&mut input.as_bytes(), Part::One).unwrap_err();
+        assert!(matches!(streamed, StreamError::Parse(err) if err.line == 3));
+        let too_long = format!("1{}", "0".repeat(28));
+        assert_eq!(Day25::parse(&too_long).err().unwrap().line, 1);
+        assert_eq!(
+            solve::<Day25>(&format!("{}\n1\n-", big), Part::One),
+            Ok(Answer::from(big))
+        );
+    }
+
+    /// Reads a SNAFU number one character at a time, most significant digit first.
+    fn brute_force_decode(snafu: &str) -> i64 {
+        snafu.chars().fold(0, |total, c| {
+            total * 5 + "=-012".find(c).unwrap() as i64 - 2
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn generated_inputs_match_brute_force(seed: u64, size in 1..200usize) {
+            let input = generate::<Day25>(seed, size);
+            let sum = input.lines().map(brute_force_decode).sum::<i64>();
+            let Ok(Answer::Text(part1)) = solve::<Day25>(&input, Part::One) else {
+                panic!("part 1 should be a SNAFU number");
+            };
+            prop_assert_eq!(brute_force_decode(&part1), sum);
+            prop_assert!(!part1.starts_with('0'));
+        }
+    }
+}
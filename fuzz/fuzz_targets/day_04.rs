@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_04::Day04;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day04::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_06::Day06;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day06::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_15::Day15;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day15::parse(input);
+});
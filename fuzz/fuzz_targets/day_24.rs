@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_24::Day24;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day24::parse(input);
+});
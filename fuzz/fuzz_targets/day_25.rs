@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_25::Day25;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day25::parse(input);
+});
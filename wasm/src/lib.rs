@@ -0,0 +1,88 @@
+use common::{
+    answer::Answer,
+    parse::ParseError,
+    solution::{solve as solve_part, Part},
+};
+use wasm_bindgen::prelude::*;
+
+type Solver = fn(&str, Part) -> Result<Answer, ParseError>;
+
+fn get_solver(day: u8) -> Option<Solver> {
+    let solver: Solver = match day {
+        1 => solve_part::<day_01::Day01>,
+        2 => solve_part::<day_02::Day02>,
+        3 => solve_part::<day_03::Day03>,
+        4 => solve_part::<day_04::Day04>,
+        5 => solve_part::<day_05::Day05>,
+        6 => solve_part::<day_06::Day06>,
+        7 => solve_part::<day_07::Day07>,
+        8 => solve_part::<day_08::Day08>,
+        9 => solve_part::<day_09::Day09>,
+        10 => solve_part::<day_10::Day10>,
+        11 => solve_part::<day_11::Day11>,
+        12 => solve_part::<day_12::Day12>,
+        13 => solve_part::<day_13::Day13>,
+        14 => solve_part::<day_14::Day14>,
+        15 => solve_part::<day_15::Day15>,
+        16 => solve_part::<day_16::Day16>,
+        17 => solve_part::<day_17::Day17>,
+        18 => solve_part::<day_18::Day18>,
+        19 => solve_part::<day_19::Day19>,
+        20 => solve_part::<day_20::Day20>,
+        21 => solve_part::<day_21::Day21>,
+        22 => solve_part::<day_22::Day22>,
+        23 => solve_part::<day_23::Day23>,
+        24 => solve_part::<day_24::Day24>,
+        25 => solve_part::<day_25::Day25>,
+        _ => return None,
+    };
+    return Some(solver);
+}
+
+/// Answers one part of a day's puzzle for the given input, printed as `aoc run` would print it.
+///
+/// Anything wrong with the request or the input comes back as a report starting with `error:`.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> String {
+    let Some(solver) = get_solver(day) else {
+        return format!("error: there is no day {}", day);
+    };
+    let Some(part) = Part::from_number(part) else {
+        return format!("error: there is no part {}", part);
+    };
+    match solver(input, part) {
+        Ok(answer) => answer.to_string(),
+        Err(err) => err.report(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn examples_are_answered_as_text() {
+        let example = include_str!("../../day-01/resources/example.txt");
+        assert_eq!(solve(1, 2, example), "45000");
+        let example = include_str!("../../day-05/resources/example.txt");
+        assert_eq!(solve(5, 1, example), "CMZ");
+        let example = include_str!("../../day-10/resources/example.txt");
+        let screen = solve(10, 2, example);
+        assert_eq!(screen.lines().count(), 6);
+        assert!(screen.starts_with("##..##..##"));
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn searches_run_without_a_system_clock() {
+        let example = include_str!("../../day-12/resources/example.txt");
+        assert_eq!(solve(12, 1, example), "31");
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn problems_are_reported_as_errors() {
+        assert!(solve(4, 1, "2-4,6-x").starts_with("error: expected a number"));
+        assert!(solve(26, 1, "").starts_with("error: there is no day 26"));
+        assert!(solve(4, 3, "").starts_with("error: there is no part 3"));
+    }
+}